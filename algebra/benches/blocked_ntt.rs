@@ -0,0 +1,40 @@
+use algebra::ntt::{FieldTableWithShoupRoot, NttTable, NumberTheoryTransform};
+use algebra::{Field, U32FieldEval};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{distributions::Uniform, prelude::*};
+
+type Fp = U32FieldEval<132120577>;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    let distr = Uniform::new_inclusive(0, Fp::MINUS_ONE);
+
+    let mut group = c.benchmark_group("field 32 ntt");
+
+    for log_n in 12..=16 {
+        let n = 1usize << log_n;
+        let table = <FieldTableWithShoupRoot<Fp>>::new(Fp::MODULUS, log_n).unwrap();
+        let mut poly: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+
+        group.bench_function(BenchmarkId::new("serial", n), |b| {
+            b.iter(|| table.transform_slice(&mut poly))
+        });
+
+        group.bench_function(BenchmarkId::new("blocked", n), |b| {
+            b.iter(|| table.blocked_transform_slice(&mut poly))
+        });
+
+        group.bench_function(BenchmarkId::new("serial inverse", n), |b| {
+            b.iter(|| table.inverse_transform_slice(&mut poly))
+        });
+
+        group.bench_function(BenchmarkId::new("blocked inverse", n), |b| {
+            b.iter(|| table.blocked_inverse_transform_slice(&mut poly))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,52 @@
+use algebra::{Field, U32FieldEval, U32MontgomeryField};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use rand::{distributions::Uniform, thread_rng, Rng};
+
+type FF = U32FieldEval<132120577>;
+type MF = U32MontgomeryField<132120577>;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    const N: usize = 1024;
+
+    let mut rng = thread_rng();
+    let dis = Uniform::new(1, FF::MODULUS_VALUE);
+
+    c.bench_function("inv one by one", |b| {
+        b.iter_batched(
+            || (&mut rng).sample_iter(dis).take(N).collect::<Vec<u32>>(),
+            |mut values| {
+                values.iter_mut().for_each(FF::inv_assign);
+                black_box(values)
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    c.bench_function("batch inv", |b| {
+        b.iter_batched(
+            || (&mut rng).sample_iter(dis).take(N).collect::<Vec<u32>>(),
+            |mut values| {
+                FF::batch_inv_assign(&mut values);
+                black_box(values)
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    let a: Vec<u32> = (&mut rng).sample_iter(dis).take(N).collect();
+    let b: Vec<u32> = (&mut rng).sample_iter(dis).take(N).collect();
+
+    c.bench_function("barrett dot product", |bench| {
+        bench.iter(|| FF::dot_product(black_box(&a), black_box(&b)));
+    });
+
+    let a: Vec<u32> = a.into_iter().map(MF::from_canonical).collect();
+    let b: Vec<u32> = b.into_iter().map(MF::from_canonical).collect();
+
+    c.bench_function("montgomery dot product", |bench| {
+        bench.iter(|| MF::dot_product(black_box(&a), black_box(&b)));
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+use algebra::{
+    modulus::BarrettModulus,
+    ntt::{FieldTableWithShoupRoot, NttTable, NumberTheoryTransform},
+    reduce::ReduceDotProduct,
+    Field, U32FieldEval,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{distributions::Uniform, prelude::*};
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    let q = 132120577u32;
+    let modulus = <BarrettModulus<u32>>::new(q);
+    let distr = Uniform::new(0, q);
+
+    let mut group = c.benchmark_group("barrett 32 slice mul");
+
+    for n in [1024usize, 8192] {
+        let mut a: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+        let b: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+
+        group.bench_function(BenchmarkId::new("scalar", n), |bench| {
+            bench.iter(|| modulus.reduce_mul_assign_slice_scalar(&mut a, &b))
+        });
+
+        group.bench_function(BenchmarkId::new("simd", n), |bench| {
+            bench.iter(|| modulus.reduce_mul_assign_slice(&mut a, &b))
+        });
+    }
+
+    group.finish();
+
+    let mut group = c.benchmark_group("barrett 32 dot product");
+
+    for n in [1024usize, 8192] {
+        let a: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+        let b: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+
+        group.bench_function(BenchmarkId::new("scalar", n), |bench| {
+            bench.iter(|| modulus.reduce_dot_product_scalar(&a, &b))
+        });
+
+        group.bench_function(BenchmarkId::new("simd", n), |bench| {
+            bench.iter(|| modulus.reduce_dot_product(&a, &b))
+        });
+    }
+
+    group.finish();
+
+    type Fp = U32FieldEval<132120577>;
+
+    let mut group = c.benchmark_group("field 32 ntt");
+
+    for log_n in [10u32, 11] {
+        let n = 1usize << log_n;
+        let table = <FieldTableWithShoupRoot<Fp>>::new(Fp::MODULUS, log_n).unwrap();
+        let mut a: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+
+        group.bench_function(BenchmarkId::new("transform", n), |bench| {
+            bench.iter(|| table.transform_slice(&mut a))
+        });
+
+        group.bench_function(BenchmarkId::new("inverse transform", n), |bench| {
+            bench.iter(|| table.inverse_transform_slice(&mut a))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
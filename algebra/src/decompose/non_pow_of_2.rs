@@ -124,6 +124,12 @@ impl<T: UnsignedInteger> NonPowOf2ApproxSignedBasis<T> {
         }
     }
 
+    /// Returns the modulus of this [`NonPowOf2ApproxSignedBasis<T>`].
+    #[inline]
+    pub fn modulus(&self) -> T {
+        self.modulus
+    }
+
     /// Returns the decompose length of this [`NonPowOf2ApproxSignedBasis<T>`].
     #[inline]
     pub fn decompose_length(&self) -> usize {
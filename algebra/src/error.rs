@@ -41,6 +41,22 @@ pub enum AlgebraError {
         /// modulus
         modulus: Box<dyn Debug>,
     },
+    /// Error that occurs when the decoded bytes do not have the expected length.
+    #[error("Expected {expected} bytes, got {len} bytes!")]
+    ByteLengthErr {
+        /// The expected length.
+        expected: usize,
+        /// The given length.
+        len: usize,
+    },
+    /// Error that occurs when a decoded value is not less than the modulus.
+    #[error("Value {value:?} is not less than the modulus {modulus:?}!")]
+    ValueOutOfRangeErr {
+        /// The value.
+        value: Box<dyn Debug>,
+        /// The modulus.
+        modulus: Box<dyn Debug>,
+    },
     /// Error that occurs when the degree is too large.
     #[error("degree should less than modulus: {degree} >= {modulus:?}")]
     TooLargeDegreeErr {
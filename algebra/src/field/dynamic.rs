@@ -0,0 +1,160 @@
+use core::marker::PhantomData;
+
+use crate::{
+    integer::{AsFrom, AsInto},
+    AlgebraError, Field,
+};
+
+/// An object safe view of a [`Field`], for dispatching on a field chosen at runtime.
+///
+/// Elements are passed as their canonical values in `[0, modulus)` widened to `u64`,
+/// whatever the value type and the stored form of the field.
+/// Inputs out of that range are reduced modulo the modulus first.
+/// Every call converts them, so this is slower than using the [`Field`] directly.
+pub trait DynField: Send + Sync {
+    /// Returns the modulus value.
+    fn modulus_value(&self) -> u64;
+
+    /// Returns the byte length of the encoding of an element.
+    fn byte_len(&self) -> usize;
+
+    /// Calculates `a + b`.
+    fn add(&self, a: u64, b: u64) -> u64;
+
+    /// Calculates `a - b`.
+    fn sub(&self, a: u64, b: u64) -> u64;
+
+    /// Calculates `-value`.
+    fn neg(&self, value: u64) -> u64;
+
+    /// Calculates `a * b`.
+    fn mul(&self, a: u64, b: u64) -> u64;
+
+    /// Calculates `value^(-1)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::NoInverse`] if `value` is `0` modulo the modulus.
+    fn inv(&self, value: u64) -> Result<u64, AlgebraError>;
+
+    /// Encodes `value` as [`Field::to_bytes_le`] does.
+    fn to_bytes_le(&self, value: u64) -> Vec<u8>;
+
+    /// Decodes an element as [`Field::from_bytes_le`] does.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Field::from_bytes_le`].
+    fn from_bytes_le(&self, bytes: &[u8]) -> Result<u64, AlgebraError>;
+}
+
+/// The [`DynField`] of the field `F`.
+///
+/// ```
+/// use algebra::{DynField, ErasedField, U32FieldEval, U64FieldEval};
+///
+/// let fields: [Box<dyn DynField>; 2] = [
+///     Box::new(ErasedField::<U32FieldEval<132120577>>::new()),
+///     Box::new(ErasedField::<U64FieldEval<1125899906826241>>::new()),
+/// ];
+/// for field in &fields {
+///     let minus_one = field.neg(1);
+///     assert_eq!(field.add(minus_one, 1), 0);
+///     assert_eq!(field.add(field.modulus_value(), 1), 1);
+/// }
+/// ```
+pub struct ErasedField<F: Field> {
+    phantom: PhantomData<fn() -> F>,
+}
+
+impl<F: Field> ErasedField<F> {
+    /// Creates a new [`ErasedField<F>`].
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+
+    /// Reduces a `u64` value modulo `F`'s modulus and converts it to the stored form of `F`.
+    #[inline]
+    fn from_u64(value: u64) -> <F as Field>::ValueT {
+        let value = value % AsInto::<u64>::as_into(F::MODULUS_VALUE);
+        F::from_canonical(<<F as Field>::ValueT>::as_from(value))
+    }
+
+    /// Converts a value in the stored form of `F` to a canonical `u64` value.
+    #[inline]
+    fn to_u64(value: <F as Field>::ValueT) -> u64 {
+        F::to_canonical(value).as_into()
+    }
+}
+
+impl<F: Field> Default for ErasedField<F> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Field> Clone for ErasedField<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Field> Copy for ErasedField<F> {}
+
+impl<F: Field> DynField for ErasedField<F> {
+    #[inline]
+    fn modulus_value(&self) -> u64 {
+        F::MODULUS_VALUE.as_into()
+    }
+
+    #[inline]
+    fn byte_len(&self) -> usize {
+        F::byte_len()
+    }
+
+    #[inline]
+    fn add(&self, a: u64, b: u64) -> u64 {
+        Self::to_u64(F::add(Self::from_u64(a), Self::from_u64(b)))
+    }
+
+    #[inline]
+    fn sub(&self, a: u64, b: u64) -> u64 {
+        Self::to_u64(F::sub(Self::from_u64(a), Self::from_u64(b)))
+    }
+
+    #[inline]
+    fn neg(&self, value: u64) -> u64 {
+        Self::to_u64(F::neg(Self::from_u64(value)))
+    }
+
+    #[inline]
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        Self::to_u64(F::mul(Self::from_u64(a), Self::from_u64(b)))
+    }
+
+    #[inline]
+    fn inv(&self, value: u64) -> Result<u64, AlgebraError> {
+        if value % self.modulus_value() == 0 {
+            return Err(AlgebraError::NoInverse {
+                value: Box::new(value),
+                modulus: Box::new(self.modulus_value()),
+            });
+        }
+        Ok(Self::to_u64(F::inv(Self::from_u64(value))))
+    }
+
+    #[inline]
+    fn to_bytes_le(&self, value: u64) -> Vec<u8> {
+        F::to_bytes_le(Self::from_u64(value))
+    }
+
+    #[inline]
+    fn from_bytes_le(&self, bytes: &[u8]) -> Result<u64, AlgebraError> {
+        F::from_bytes_le(bytes).map(Self::to_u64)
+    }
+}
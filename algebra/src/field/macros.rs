@@ -85,3 +85,47 @@ macro_rules! impl_barrett_field {
         }
     };
 }
+
+macro_rules! impl_montgomery_field {
+    ($(#[$cfg:meta])* impl $Vis:vis $FieldName:ident<$ValueT:ty>) => {
+        /// This define a field based the montgomery multiplication.
+        ///
+        /// Its values are in montgomery form, use [`Self::from_canonical`] and
+        /// [`Self::to_canonical`] to convert them from and to the normal form.
+        ///
+        /// It does not implement [`NttField`](crate::NttField): the gadget decomposition of
+        /// key switching and blind rotation works on the stored values, which would be wrong
+        /// in montgomery form.
+        ///
+        /// ```compile_fail
+        /// fn ntt_field<F: algebra::NttField>() {}
+        ///
+        #[doc = concat!("ntt_field::<algebra::", stringify!($FieldName), "<132120577>>();")]
+        /// ```
+        $(#[$cfg])*
+        $Vis struct $FieldName<const P:$ValueT>;
+
+        impl<const P:$ValueT> $crate::Field for $FieldName<P> {
+            type ValueT = $ValueT;
+            type Modulus = $crate::modulus::MontgomeryModulus<$ValueT>;
+
+            const MODULUS_VALUE: Self::ValueT = P;
+            const MODULUS: Self::Modulus = Self::Modulus::new(P);
+            const ZERO: Self::ValueT = 0;
+            const ONE: Self::ValueT = Self::MODULUS.one();
+            const MINUS_ONE: Self::ValueT = P - Self::MODULUS.one();
+
+            /// Converts a canonical `value` in `[0, P)` to montgomery form.
+            #[inline]
+            fn from_canonical(value: Self::ValueT) -> Self::ValueT {
+                Self::MODULUS.to_montgomery(value)
+            }
+
+            /// Converts a `value` in montgomery form to the canonical value in `[0, P)`.
+            #[inline]
+            fn to_canonical(value: Self::ValueT) -> Self::ValueT {
+                Self::MODULUS.from_montgomery(value)
+            }
+        }
+    };
+}
@@ -1,15 +1,19 @@
-use crate::integer::UnsignedInteger;
+use crate::integer::{ct_eq, ct_select, AsInto, UnsignedInteger};
 use crate::numeric::Numeric;
 use crate::reduce::*;
+use crate::AlgebraError;
 
 #[macro_use]
 mod macros;
+mod dynamic;
 mod impls;
 mod ntt;
 
+pub use dynamic::{DynField, ErasedField};
 pub use impls::f32::U32FieldEval;
 pub use impls::f64::U64FieldEval;
 pub use ntt::NttField;
+pub use subtle::Choice;
 
 /// An abstract for field evaluator.
 pub trait Field: Sized + Clone + Copy {
@@ -140,6 +144,52 @@ pub trait Field: Sized + Clone + Copy {
         Self::MODULUS.reduce_inv_assign(value);
     }
 
+    /// Calculates `value^(-1)` for every non-zero value of `values`,
+    /// zeros are left as zero.
+    ///
+    /// It uses Montgomery's trick, which costs one inversion and about `3n` multiplications.
+    fn batch_inv_assign(values: &mut [Self::ValueT]) {
+        let mut prefix = Vec::with_capacity(values.len());
+        let mut acc = Self::ONE;
+        for &value in values.iter() {
+            prefix.push(acc);
+            if !value.is_zero() {
+                Self::mul_assign(&mut acc, value);
+            }
+        }
+
+        let mut acc_inv = Self::inv(acc);
+        for (value, prefix) in values.iter_mut().zip(prefix).rev() {
+            if !value.is_zero() {
+                let value_inv = Self::mul(acc_inv, prefix);
+                Self::mul_assign(&mut acc_inv, *value);
+                *value = value_inv;
+            }
+        }
+    }
+
+    /// Returns `a` if `choice` is `0`, or `b` if `choice` is `1`,
+    /// without branching on `choice`, see [`ct_select`].
+    #[inline]
+    fn conditional_select(a: Self::ValueT, b: Self::ValueT, choice: Choice) -> Self::ValueT {
+        ct_select(a, b, choice)
+    }
+
+    /// Sets `a = b` if `choice` is `1`, leaves `a` unchanged if `choice` is `0`,
+    /// without branching on `choice`.
+    #[inline]
+    fn conditional_assign(a: &mut Self::ValueT, b: Self::ValueT, choice: Choice) {
+        *a = Self::conditional_select(*a, b, choice);
+    }
+
+    /// Returns `1` if `a == b`, or `0` otherwise, see [`ct_eq`].
+    ///
+    /// Elements are stored reduced, so comparing the stored values is enough.
+    #[inline]
+    fn ct_eq(a: Self::ValueT, b: Self::ValueT) -> Choice {
+        ct_eq(a, b)
+    }
+
     /// Calculates `a / b`.
     #[inline]
     fn div(a: Self::ValueT, b: Self::ValueT) -> Self::ValueT {
@@ -151,7 +201,90 @@ pub trait Field: Sized + Clone + Copy {
     fn div_assign(a: &mut Self::ValueT, b: Self::ValueT) {
         Self::MODULUS.reduce_div_assign(a, b);
     }
+
+    /// Converts a canonical `value` in `[0, MODULUS_VALUE)` to the stored form.
+    ///
+    /// It is the identity except for fields storing values in another form,
+    /// like the montgomery fields.
+    #[inline]
+    fn from_canonical(value: Self::ValueT) -> Self::ValueT {
+        value
+    }
+
+    /// Converts a stored `value` to the canonical value in `[0, MODULUS_VALUE)`.
+    #[inline]
+    fn to_canonical(value: Self::ValueT) -> Self::ValueT {
+        value
+    }
+
+    /// Returns the byte length of the encoding of an element,
+    /// the byte length of [`Self::MODULUS_VALUE`].
+    #[inline]
+    fn byte_len() -> usize {
+        let bits = <Self::ValueT>::BITS - Self::MODULUS_VALUE.leading_zeros();
+        bits.div_ceil(8) as usize
+    }
+
+    /// Encodes the canonical value of `value` in [`Self::byte_len`] little-endian bytes.
+    #[inline]
+    fn to_bytes_le(value: Self::ValueT) -> Vec<u8> {
+        let value = Self::to_canonical(value);
+        (0..Self::byte_len())
+            .map(|i| -> u8 { (value >> (i as u32 * 8)).as_into() })
+            .collect()
+    }
+
+    /// Encodes the canonical value of `value` in [`Self::byte_len`] big-endian bytes.
+    #[inline]
+    fn to_bytes_be(value: Self::ValueT) -> Vec<u8> {
+        let mut bytes = Self::to_bytes_le(value);
+        bytes.reverse();
+        bytes
+    }
+
+    /// Decodes an element from [`Self::byte_len`] little-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` is not [`Self::byte_len`]
+    /// or the decoded value is not less than [`Self::MODULUS_VALUE`].
+    #[inline]
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self::ValueT, AlgebraError> {
+        let byte_len = Self::byte_len();
+        if bytes.len() != byte_len {
+            return Err(AlgebraError::ByteLengthErr {
+                expected: byte_len,
+                len: bytes.len(),
+            });
+        }
+        let value = bytes.iter().rev().fold(<Self::ValueT>::ZERO, |acc, &b| {
+            // the shift is split since it may be the full bit width for `u8`.
+            ((acc << 4u32) << 4u32) | <Self::ValueT>::as_from(b)
+        });
+        if value >= Self::MODULUS_VALUE {
+            return Err(AlgebraError::ValueOutOfRangeErr {
+                value: Box::new(value),
+                modulus: Box::new(Self::MODULUS_VALUE),
+            });
+        }
+        Ok(Self::from_canonical(value))
+    }
+
+    /// Decodes an element from [`Self::byte_len`] big-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::from_bytes_le`].
+    #[inline]
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self::ValueT, AlgebraError> {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Self::from_bytes_le(&bytes)
+    }
 }
 
 impl_barrett_field!(#[derive(Clone, Copy)] impl pub U8FieldEval<u8>);
 impl_barrett_field!(#[derive(Clone, Copy)] impl pub U16FieldEval<u16>);
+
+impl_montgomery_field!(#[derive(Clone, Copy)] impl pub U32MontgomeryField<u32>);
+impl_montgomery_field!(#[derive(Clone, Copy)] impl pub U64MontgomeryField<u64>);
@@ -9,7 +9,10 @@ use super::Field;
 pub trait NttField: Field {
     /// An abstraction over the data structure used to store precomputed values for NTT.
     type Table: NttTable<ValueT = Self::ValueT>
-        + NumberTheoryTransform<CoeffPoly = FieldPolynomial<Self>, NttPoly = FieldNttPolynomial<Self>>;
+        + NumberTheoryTransform<CoeffPoly = FieldPolynomial<Self>, NttPoly = FieldNttPolynomial<Self>>
+        + Send
+        + Sync
+        + 'static;
 
     /// Generate the ntt table of the ntt field with desired `log_n`.
     fn generate_ntt_table(log_n: u32) -> Result<Self::Table, crate::AlgebraError>;
@@ -0,0 +1,33 @@
+use subtle::Choice;
+
+use super::UnsignedInteger;
+
+/// Returns `T::MAX` if `choice` is `1`, or `0` if `choice` is `0`.
+#[inline(always)]
+pub fn ct_mask<T: UnsignedInteger>(choice: Choice) -> T {
+    T::ZERO.wrapping_sub(T::as_from(choice.unwrap_u8()))
+}
+
+/// Returns `T::MAX` if `a == b`, or `0` otherwise, without branching.
+#[inline(always)]
+pub fn ct_eq_mask<T: UnsignedInteger>(a: T, b: T) -> T {
+    let x = a ^ b;
+    // the top bit of `x | -x` is set if and only if `x` is not zero.
+    let non_zero = (x | x.wrapping_neg()) >> (T::BITS - 1);
+    non_zero.wrapping_sub(T::ONE)
+}
+
+/// Returns `1` if `a == b`, or `0` otherwise, like [`subtle::ConstantTimeEq`].
+#[inline]
+pub fn ct_eq<T: UnsignedInteger>(a: T, b: T) -> Choice {
+    let bit: u8 = (ct_eq_mask(a, b) & T::ONE).as_into();
+    Choice::from(bit)
+}
+
+/// Returns `a` if `choice` is `0`, or `b` if `choice` is `1`,
+/// like [`subtle::ConditionallySelectable`], without branching on `choice`.
+#[inline]
+pub fn ct_select<T: UnsignedInteger>(a: T, b: T, choice: Choice) -> T {
+    let mask: T = ct_mask(choice);
+    (a & !mask) | (b & mask)
+}
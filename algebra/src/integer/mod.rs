@@ -4,6 +4,7 @@ mod bits;
 mod bounded;
 mod cast;
 mod checked;
+mod constant_time;
 mod overflowing;
 mod two;
 mod wrapping;
@@ -24,6 +25,7 @@ pub use bits::Bits;
 pub use bounded::ConstBounded;
 pub use cast::*;
 pub use checked::*;
+pub use constant_time::{ct_eq, ct_eq_mask, ct_mask, ct_select};
 pub use overflowing::*;
 pub use two::ConstTwo;
 pub use wrapping::*;
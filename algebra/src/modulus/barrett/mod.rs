@@ -10,6 +10,8 @@ use crate::{
 mod macros;
 mod ops;
 mod root;
+#[cfg(feature = "simd")]
+mod simd;
 
 /// A modulus, using barrett reduction algorithm.
 ///
@@ -73,6 +75,18 @@ impl<T: Numeric> Modulus<T> for BarrettModulus<T> {
         }
     }
 
+    #[inline]
+    fn try_from_value(value: ModulusValue<T>) -> Option<Self> {
+        match value {
+            ModulusValue::Native => None,
+            ModulusValue::PowerOf2(value)
+            | ModulusValue::Prime(value)
+            | ModulusValue::Others(value) => (value > T::ONE
+                && T::BITS - value.leading_zeros() < T::BITS - 1)
+                .then(|| Self::new_generic(value)),
+        }
+    }
+
     #[inline]
     fn modulus_value(&self) -> ModulusValue<T> {
         ModulusValue::Others(self.value)
@@ -480,8 +480,13 @@ impl<T: Numeric> ReduceDotProduct<T> for BarrettModulus<T> {
 
         debug_assert_eq!(a.len(), b.len());
 
-        a.chunks_exact(16)
-            .zip(b.chunks_exact(16))
+        #[cfg(feature = "simd")]
+        if let Some(result) = super::simd::reduce_dot_product(self, a, b) {
+            return result;
+        }
+
+        a.chunks(16)
+            .zip(b.chunks(16))
             .map(|(a_s, b_s)| {
                 let mut c: [T; 2] = [T::ZERO, T::ZERO];
                 for (&a, &b) in a_s.iter().zip(b_s) {
@@ -0,0 +1,333 @@
+use crate::{
+    integer::{AsFrom, AsInto},
+    modulus::simd::{as_u32_slice, detect, Isa},
+    numeric::Numeric,
+    reduce::{ReduceAdd, ReduceMul, ReduceMulAssign},
+};
+
+use super::BarrettModulus;
+
+/// The number of vectors accumulated by the dot product kernels before
+/// their lanes are summed, small enough that the `u64` lanes can't overflow.
+const DOT_PRODUCT_BLOCK: usize = 1 << 20;
+
+impl BarrettModulus<u32> {
+    /// Calculates `a[i] = a[i] * b[i] mod q` for every `i`.
+    ///
+    /// On `x86_64` CPUs with AVX2 and `aarch64` CPUs with NEON, which are detected
+    /// at runtime, eight or four products are reduced at once with Montgomery
+    /// multiplication. Otherwise, or for an even modulus, it falls back to
+    /// [`Self::reduce_mul_assign_slice_scalar`]. Both paths give the same results.
+    ///
+    /// All values must be less than the modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[inline]
+    pub fn reduce_mul_assign_slice(self, a: &mut [u32], b: &[u32]) {
+        assert_eq!(a.len(), b.len());
+
+        if self.value & 1 == 1 {
+            match detect() {
+                #[cfg(target_arch = "x86_64")]
+                // SAFETY: AVX2 is available on this CPU.
+                Some(Isa::Avx2) => return unsafe { avx2::reduce_mul_assign_slice(self, a, b) },
+                #[cfg(target_arch = "aarch64")]
+                // SAFETY: NEON is available on this CPU.
+                Some(Isa::Neon) => return unsafe { neon::reduce_mul_assign_slice(self, a, b) },
+                _ => {}
+            }
+        }
+
+        self.reduce_mul_assign_slice_scalar(a, b);
+    }
+
+    /// Calculates `a[i] = a[i] * b[i] mod q` for every `i`, one by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[inline]
+    pub fn reduce_mul_assign_slice_scalar(self, a: &mut [u32], b: &[u32]) {
+        assert_eq!(a.len(), b.len());
+
+        a.iter_mut()
+            .zip(b)
+            .for_each(|(x, &y)| self.reduce_mul_assign(x, y));
+    }
+
+    /// Calculates `∑a[i] * b[i] mod q`, one product by one.
+    ///
+    /// It is the reference of the SIMD path of
+    /// [`reduce_dot_product`](crate::reduce::ReduceDotProduct::reduce_dot_product).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[inline]
+    pub fn reduce_dot_product_scalar(self, a: &[u32], b: &[u32]) -> u32 {
+        assert_eq!(a.len(), b.len());
+
+        a.iter().zip(b).fold(0, |acc, (&x, &y)| {
+            self.value.reduce_add(acc, self.reduce_mul(x, y))
+        })
+    }
+}
+
+/// The SIMD path of the dot product of [`BarrettModulus<T>`],
+/// returns `None` if `T` is not `u32` or this CPU has no kernel.
+///
+/// The products are accumulated exactly in `u64` lanes, split into their
+/// low and high 32 bits, and reduced once at the end.
+#[inline]
+pub(super) fn reduce_dot_product<T: Numeric>(
+    modulus: BarrettModulus<T>,
+    a: &[T],
+    b: &[T],
+) -> Option<T> {
+    let isa = detect()?;
+    let (a, b) = (as_u32_slice(a)?, as_u32_slice(b)?);
+    if a.len() < isa.lanes() {
+        return None;
+    }
+
+    let (sum, done) = match isa {
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: AVX2 is available on this CPU.
+        Isa::Avx2 => unsafe { avx2::dot_product(a, b) },
+        #[cfg(target_arch = "aarch64")]
+        // SAFETY: NEON is available on this CPU.
+        Isa::Neon => unsafe { neon::dot_product(a, b) },
+    };
+    let sum = a[done..]
+        .iter()
+        .zip(&b[done..])
+        .fold(sum, |acc, (&x, &y)| acc + x as u128 * y as u128);
+
+    let q: u32 = modulus.value.as_into();
+    Some(T::as_from((sum % q as u128) as u32))
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use core::arch::x86_64::*;
+
+    use crate::reduce::ReduceMulAssign;
+
+    use super::{BarrettModulus, DOT_PRODUCT_BLOCK};
+
+    /// The AVX2 version of [`BarrettModulus::reduce_mul_assign_slice`].
+    ///
+    /// With `R = 2³²`, `a * b mod q = REDC(REDC(a * b) * (R² mod q))`,
+    /// which needs an odd modulus less than `2³¹`.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn reduce_mul_assign_slice(
+        modulus: BarrettModulus<u32>,
+        a: &mut [u32],
+        b: &[u32],
+    ) {
+        let q = modulus.value();
+
+        // `q⁻¹ mod 2³²` by Newton iteration, each step doubles the correct bits.
+        let mut inv = q;
+        for _ in 0..4 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(q.wrapping_mul(inv)));
+        }
+        let r2 = ((1u128 << 64) % q as u128) as u32;
+
+        let vq = _mm256_set1_epi32(q as i32);
+        let vneg_inv = _mm256_set1_epi32(inv.wrapping_neg() as i32);
+        let vr2 = _mm256_set1_epi32(r2 as i32);
+
+        let mut a_chunks = a.chunks_exact_mut(8);
+        let mut b_chunks = b.chunks_exact(8);
+        for (x, y) in (&mut a_chunks).zip(&mut b_chunks) {
+            let vx = _mm256_loadu_si256(x.as_ptr() as *const __m256i);
+            let vy = _mm256_loadu_si256(y.as_ptr() as *const __m256i);
+
+            let t = mont_mul(vx, vy, vq, vneg_inv);
+            let r = mont_mul(t, vr2, vq, vneg_inv);
+
+            _mm256_storeu_si256(x.as_mut_ptr() as *mut __m256i, r);
+        }
+
+        a_chunks
+            .into_remainder()
+            .iter_mut()
+            .zip(b_chunks.remainder())
+            .for_each(|(x, &y)| modulus.reduce_mul_assign(x, y));
+    }
+
+    /// Returns `∑a[i] * b[i]` over the whole chunks of eight values,
+    /// and the count of those values.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn dot_product(a: &[u32], b: &[u32]) -> (u128, usize) {
+        let done = a.len() / 8 * 8;
+        let mask = _mm256_set1_epi64x(u32::MAX as i64);
+
+        let mut sum = 0u128;
+        for (a_block, b_block) in a[..done]
+            .chunks(8 * DOT_PRODUCT_BLOCK)
+            .zip(b[..done].chunks(8 * DOT_PRODUCT_BLOCK))
+        {
+            let mut lo = _mm256_setzero_si256();
+            let mut hi = _mm256_setzero_si256();
+            for (x, y) in a_block.chunks_exact(8).zip(b_block.chunks_exact(8)) {
+                let vx = _mm256_loadu_si256(x.as_ptr() as *const __m256i);
+                let vy = _mm256_loadu_si256(y.as_ptr() as *const __m256i);
+
+                let even = _mm256_mul_epu32(vx, vy);
+                let odd =
+                    _mm256_mul_epu32(_mm256_srli_epi64::<32>(vx), _mm256_srli_epi64::<32>(vy));
+
+                lo = _mm256_add_epi64(lo, _mm256_and_si256(even, mask));
+                lo = _mm256_add_epi64(lo, _mm256_and_si256(odd, mask));
+                hi = _mm256_add_epi64(hi, _mm256_srli_epi64::<32>(even));
+                hi = _mm256_add_epi64(hi, _mm256_srli_epi64::<32>(odd));
+            }
+            sum += sum_lanes(lo) + (sum_lanes(hi) << 32);
+        }
+
+        (sum, done)
+    }
+
+    /// Sums the four `u64` lanes.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn sum_lanes(v: __m256i) -> u128 {
+        let mut lanes = [0u64; 4];
+        _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, v);
+        lanes.iter().map(|&l| l as u128).sum()
+    }
+
+    /// Calculates `x * y * 2⁻³² mod q` for eight `u32` lanes.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mont_mul(x: __m256i, y: __m256i, q: __m256i, neg_inv: __m256i) -> __m256i {
+        let even = redc(_mm256_mul_epu32(x, y), q, neg_inv);
+        let odd = redc(
+            _mm256_mul_epu32(_mm256_srli_epi64::<32>(x), _mm256_srli_epi64::<32>(y)),
+            q,
+            neg_inv,
+        );
+
+        let t = _mm256_blend_epi32::<0b1010_1010>(even, _mm256_slli_epi64::<32>(odd));
+        // `t < 2q`, if `t < q` then `t - q` wraps around and is larger than `t`.
+        _mm256_min_epu32(t, _mm256_sub_epi32(t, q))
+    }
+
+    /// Montgomery reduction `(t + m * q) / 2³²` of four `u64` lanes,
+    /// where `m = t * (-q⁻¹) mod 2³²`. The result is less than `2q`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn redc(t: __m256i, q: __m256i, neg_inv: __m256i) -> __m256i {
+        let m = _mm256_mul_epu32(t, neg_inv);
+        let mq = _mm256_mul_epu32(m, q);
+        _mm256_srli_epi64::<32>(_mm256_add_epi64(t, mq))
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use core::arch::aarch64::*;
+
+    use crate::reduce::ReduceMulAssign;
+
+    use super::{BarrettModulus, DOT_PRODUCT_BLOCK};
+
+    /// The NEON version of [`BarrettModulus::reduce_mul_assign_slice`],
+    /// with the same Montgomery multiplication as the AVX2 version.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn reduce_mul_assign_slice(
+        modulus: BarrettModulus<u32>,
+        a: &mut [u32],
+        b: &[u32],
+    ) {
+        let q = modulus.value();
+
+        // `q⁻¹ mod 2³²` by Newton iteration, each step doubles the correct bits.
+        let mut inv = q;
+        for _ in 0..4 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(q.wrapping_mul(inv)));
+        }
+        let r2 = ((1u128 << 64) % q as u128) as u32;
+
+        let vq = vdupq_n_u32(q);
+        let vneg_inv = vdupq_n_u32(inv.wrapping_neg());
+        let vr2 = vdupq_n_u32(r2);
+
+        let mut a_chunks = a.chunks_exact_mut(4);
+        let mut b_chunks = b.chunks_exact(4);
+        for (x, y) in (&mut a_chunks).zip(&mut b_chunks) {
+            let vx = vld1q_u32(x.as_ptr());
+            let vy = vld1q_u32(y.as_ptr());
+
+            let t = mont_mul(vx, vy, vq, vneg_inv);
+            let r = mont_mul(t, vr2, vq, vneg_inv);
+
+            vst1q_u32(x.as_mut_ptr(), r);
+        }
+
+        a_chunks
+            .into_remainder()
+            .iter_mut()
+            .zip(b_chunks.remainder())
+            .for_each(|(x, &y)| modulus.reduce_mul_assign(x, y));
+    }
+
+    /// Returns `∑a[i] * b[i]` over the whole chunks of four values,
+    /// and the count of those values.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn dot_product(a: &[u32], b: &[u32]) -> (u128, usize) {
+        let done = a.len() / 4 * 4;
+
+        let mut sum = 0u128;
+        for (a_block, b_block) in a[..done]
+            .chunks(4 * DOT_PRODUCT_BLOCK)
+            .zip(b[..done].chunks(4 * DOT_PRODUCT_BLOCK))
+        {
+            let mut lo = vdupq_n_u64(0);
+            let mut hi = vdupq_n_u64(0);
+            for (x, y) in a_block.chunks_exact(4).zip(b_block.chunks_exact(4)) {
+                let vx = vld1q_u32(x.as_ptr());
+                let vy = vld1q_u32(y.as_ptr());
+
+                let p0 = vreinterpretq_u32_u64(vmull_u32(vget_low_u32(vx), vget_low_u32(vy)));
+                let p1 = vreinterpretq_u32_u64(vmull_high_u32(vx, vy));
+
+                lo = vpadalq_u32(lo, vuzp1q_u32(p0, p1));
+                hi = vpadalq_u32(hi, vuzp2q_u32(p0, p1));
+            }
+            sum += vaddvq_u64(lo) as u128 + ((vaddvq_u64(hi) as u128) << 32);
+        }
+
+        (sum, done)
+    }
+
+    /// Calculates `x * y * 2⁻³² mod q` for four `u32` lanes.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn mont_mul(
+        x: uint32x4_t,
+        y: uint32x4_t,
+        q: uint32x4_t,
+        neg_inv: uint32x4_t,
+    ) -> uint32x4_t {
+        let t0 = vreinterpretq_u32_u64(vmull_u32(vget_low_u32(x), vget_low_u32(y)));
+        let t1 = vreinterpretq_u32_u64(vmull_high_u32(x, y));
+        let t_lo = vuzp1q_u32(t0, t1);
+        let t_hi = vuzp2q_u32(t0, t1);
+
+        let m = vmulq_u32(t_lo, neg_inv);
+        let mq0 = vreinterpretq_u32_u64(vmull_u32(vget_low_u32(m), vget_low_u32(q)));
+        let mq1 = vreinterpretq_u32_u64(vmull_high_u32(m, q));
+        let mq_hi = vuzp2q_u32(mq0, mq1);
+
+        // The low halves of `t + m * q` sum to `0 mod 2³²`,
+        // so they carry exactly when `t_lo` is not zero, and `vtstq` is `-1` then.
+        let t = vsubq_u32(vaddq_u32(t_hi, mq_hi), vtstq_u32(t_lo, t_lo));
+        // `t < 2q`, if `t < q` then `t - q` wraps around and is larger than `t`.
+        vminq_u32(t, vsubq_u32(t, q))
+    }
+}
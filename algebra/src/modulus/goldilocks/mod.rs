@@ -0,0 +1,129 @@
+use crate::reduce::{Modulus, ModulusValue};
+
+mod ops;
+
+/// The Goldilocks prime modulus `p = 2⁶⁴ - 2³² + 1`.
+///
+/// [`BarrettModulus<u64>`](crate::modulus::BarrettModulus) only supports modulus with
+/// less than 63 bits, this type uses the special form of `p` to reduce `u128` values
+/// with several additions and subtractions instead.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GoldilocksModulus;
+
+impl GoldilocksModulus {
+    /// The value of the Goldilocks prime, `2⁶⁴ - 2³² + 1`.
+    pub const VALUE: u64 = 0xFFFF_FFFF_0000_0001;
+
+    /// `2⁶⁴ mod p`, which is equal to `2³² - 1`.
+    const EPSILON: u64 = 0xFFFF_FFFF;
+
+    /// Creates a new [`GoldilocksModulus`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns the value of this [`GoldilocksModulus`].
+    #[inline]
+    pub const fn value(&self) -> u64 {
+        Self::VALUE
+    }
+}
+
+impl Modulus<u64> for GoldilocksModulus {
+    #[inline]
+    fn from_value(value: ModulusValue<u64>) -> Self {
+        match value {
+            ModulusValue::Prime(Self::VALUE) | ModulusValue::Others(Self::VALUE) => Self,
+            _ => panic!("The value is not the goldilocks prime."),
+        }
+    }
+
+    #[inline]
+    fn try_from_value(value: ModulusValue<u64>) -> Option<Self> {
+        match value {
+            ModulusValue::Prime(Self::VALUE) | ModulusValue::Others(Self::VALUE) => Some(Self),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn modulus_value(&self) -> ModulusValue<u64> {
+        ModulusValue::Prime(Self::VALUE)
+    }
+
+    #[inline]
+    fn modulus_minus_one(&self) -> u64 {
+        Self::VALUE - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{distributions::Uniform, prelude::*};
+
+    use crate::reduce::*;
+
+    use super::*;
+
+    const P: u64 = GoldilocksModulus::VALUE;
+
+    #[test]
+    fn test_reduce() {
+        let mut rng = thread_rng();
+        let modulus = GoldilocksModulus::new();
+        let dis = Uniform::new(0, P);
+
+        for _ in 0..1000 {
+            let v: u64 = rng.gen();
+            assert_eq!(modulus.reduce(v), v % P);
+
+            let a: u64 = rng.sample(dis);
+            let b: u64 = rng.sample(dis);
+            let c: u64 = rng.sample(dis);
+
+            assert_eq!(
+                modulus.reduce_add(a, b),
+                ((a as u128 + b as u128) % P as u128) as u64
+            );
+            assert_eq!(
+                modulus.reduce_sub(a, b),
+                ((P as u128 + a as u128 - b as u128) % P as u128) as u64
+            );
+            assert_eq!(
+                modulus.reduce_mul(a, b),
+                ((a as u128 * b as u128) % P as u128) as u64
+            );
+            assert_eq!(
+                modulus.reduce_mul_add(a, b, c),
+                ((a as u128 * b as u128 + c as u128) % P as u128) as u64
+            );
+            assert_eq!(modulus.reduce_add(a, modulus.reduce_neg(a)), 0);
+
+            if a != 0 {
+                assert_eq!(modulus.reduce_mul(a, modulus.reduce_inv(a)), 1);
+            }
+        }
+
+        assert_eq!(modulus.reduce_neg(0), 0);
+        assert_eq!(modulus.reduce_mul(P - 1, P - 1), 1);
+        assert_eq!(modulus.reduce_exp(7u64, P - 1), 1);
+        assert!(modulus.try_reduce_inv(0).is_err());
+    }
+
+    #[test]
+    fn test_dot_product() {
+        let mut rng = thread_rng();
+        let modulus = GoldilocksModulus::new();
+        let dis = Uniform::new(0, P);
+
+        let a: Vec<u64> = (&mut rng).sample_iter(dis).take(256).collect();
+        let b: Vec<u64> = (&mut rng).sample_iter(dis).take(256).collect();
+
+        let expected = a.iter().zip(&b).fold(0u128, |acc, (&x, &y)| {
+            (acc + x as u128 * y as u128) % P as u128
+        }) as u64;
+
+        assert_eq!(modulus.reduce_dot_product(&a, &b), expected);
+    }
+}
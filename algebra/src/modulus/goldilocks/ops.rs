@@ -0,0 +1,315 @@
+use crate::{integer::UnsignedInteger, reduce::*, AlgebraError};
+
+use super::GoldilocksModulus;
+
+const P: u64 = GoldilocksModulus::VALUE;
+const EPSILON: u64 = GoldilocksModulus::EPSILON;
+
+/// Reduces a `u128` value with `2⁶⁴ ≡ 2³² - 1` and `2⁹⁶ ≡ -1 (mod p)`.
+#[inline(always)]
+fn reduce_u128(value: u128) -> u64 {
+    let lo = value as u64;
+    let hi = (value >> 64) as u64;
+    let hi_hi = hi >> 32;
+    let hi_lo = hi & EPSILON;
+
+    let (mut t0, borrow) = lo.overflowing_sub(hi_hi);
+    if borrow {
+        t0 -= EPSILON;
+    }
+    let t1 = hi_lo * EPSILON;
+
+    let (t2, carry) = t0.overflowing_add(t1);
+    reduce_once(t2 + EPSILON * (carry as u64))
+}
+
+/// Reduces a `u64` value, any `u64` value is less than `2p`.
+#[inline(always)]
+fn reduce_once(value: u64) -> u64 {
+    if value >= P {
+        value - P
+    } else {
+        value
+    }
+}
+
+impl Reduce<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline(always)]
+    fn reduce(self, value: u64) -> Self::Output {
+        reduce_once(value)
+    }
+}
+
+impl ReduceAssign<u64> for GoldilocksModulus {
+    #[inline(always)]
+    fn reduce_assign(self, value: &mut u64) {
+        *value = reduce_once(*value);
+    }
+}
+
+impl ReduceOnce<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline(always)]
+    fn reduce_once(self, value: u64) -> Self::Output {
+        reduce_once(value)
+    }
+}
+
+impl ReduceOnceAssign<u64> for GoldilocksModulus {
+    #[inline(always)]
+    fn reduce_once_assign(self, value: &mut u64) {
+        *value = reduce_once(*value);
+    }
+}
+
+impl ReduceAdd<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline(always)]
+    fn reduce_add(self, a: u64, b: u64) -> Self::Output {
+        let (sum, over) = a.overflowing_add(b);
+        if over {
+            sum + EPSILON
+        } else {
+            reduce_once(sum)
+        }
+    }
+}
+
+impl ReduceAddAssign<u64> for GoldilocksModulus {
+    #[inline(always)]
+    fn reduce_add_assign(self, a: &mut u64, b: u64) {
+        *a = self.reduce_add(*a, b);
+    }
+}
+
+impl ReduceDouble<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline(always)]
+    fn reduce_double(self, value: u64) -> Self::Output {
+        self.reduce_add(value, value)
+    }
+}
+
+impl ReduceDoubleAssign<u64> for GoldilocksModulus {
+    #[inline(always)]
+    fn reduce_double_assign(self, value: &mut u64) {
+        *value = self.reduce_add(*value, *value);
+    }
+}
+
+impl ReduceSub<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline(always)]
+    fn reduce_sub(self, a: u64, b: u64) -> Self::Output {
+        let (diff, borrow) = a.overflowing_sub(b);
+        if borrow {
+            diff - EPSILON
+        } else {
+            diff
+        }
+    }
+}
+
+impl ReduceSubAssign<u64> for GoldilocksModulus {
+    #[inline(always)]
+    fn reduce_sub_assign(self, a: &mut u64, b: u64) {
+        *a = self.reduce_sub(*a, b);
+    }
+}
+
+impl ReduceNeg<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline(always)]
+    fn reduce_neg(self, value: u64) -> Self::Output {
+        if value == 0 {
+            0
+        } else {
+            P - value
+        }
+    }
+}
+
+impl ReduceNegAssign<u64> for GoldilocksModulus {
+    #[inline(always)]
+    fn reduce_neg_assign(self, value: &mut u64) {
+        *value = self.reduce_neg(*value);
+    }
+}
+
+impl ReduceMul<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline(always)]
+    fn reduce_mul(self, a: u64, b: u64) -> Self::Output {
+        reduce_u128(a as u128 * b as u128)
+    }
+}
+
+impl ReduceMulAssign<u64> for GoldilocksModulus {
+    #[inline(always)]
+    fn reduce_mul_assign(self, a: &mut u64, b: u64) {
+        *a = reduce_u128(*a as u128 * b as u128);
+    }
+}
+
+impl ReduceSquare<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline(always)]
+    fn reduce_square(self, value: u64) -> Self::Output {
+        reduce_u128(value as u128 * value as u128)
+    }
+}
+
+impl ReduceSquareAssign<u64> for GoldilocksModulus {
+    #[inline(always)]
+    fn reduce_square_assign(self, value: &mut u64) {
+        *value = reduce_u128(*value as u128 * *value as u128);
+    }
+}
+
+impl ReduceMulAdd<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline(always)]
+    fn reduce_mul_add(self, a: u64, b: u64, c: u64) -> Self::Output {
+        reduce_u128(a as u128 * b as u128 + c as u128)
+    }
+}
+
+impl ReduceMulAddAssign<u64> for GoldilocksModulus {
+    #[inline(always)]
+    fn reduce_mul_add_assign(self, a: &mut u64, b: u64, c: u64) {
+        *a = reduce_u128(*a as u128 * b as u128 + c as u128);
+    }
+}
+
+impl ReduceExp<u64> for GoldilocksModulus {
+    #[inline]
+    fn reduce_exp<E: UnsignedInteger>(self, base: u64, mut exp: E) -> u64 {
+        if exp.is_zero() {
+            return 1;
+        }
+
+        if base == 0 {
+            return 0;
+        }
+
+        debug_assert!(base < P);
+
+        let mut power = base;
+
+        let exp_trailing_zeros = exp.trailing_zeros();
+        if exp_trailing_zeros > 0 {
+            for _ in 0..exp_trailing_zeros {
+                self.reduce_square_assign(&mut power);
+            }
+            exp >>= exp_trailing_zeros;
+        }
+
+        if exp.is_one() {
+            return power;
+        }
+
+        let mut intermediate = power;
+        for _ in 1..(E::BITS - exp.leading_zeros()) {
+            exp >>= 1;
+            self.reduce_square_assign(&mut power);
+            if !(exp & E::ONE).is_zero() {
+                self.reduce_mul_assign(&mut intermediate, power);
+            }
+        }
+        intermediate
+    }
+}
+
+impl ReduceExpPowOf2<u64> for GoldilocksModulus {
+    #[inline]
+    fn reduce_exp_power_of_2(self, base: u64, exp_log: u32) -> u64 {
+        if base == 0 {
+            return 0;
+        }
+
+        let mut power = base;
+
+        for _ in 0..exp_log {
+            self.reduce_square_assign(&mut power);
+        }
+
+        power
+    }
+}
+
+impl ReduceDotProduct<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline]
+    fn reduce_dot_product(self, a: impl AsRef<[u64]>, b: impl AsRef<[u64]>) -> Self::Output {
+        let a = a.as_ref();
+        let b = b.as_ref();
+
+        debug_assert_eq!(a.len(), b.len());
+
+        a.iter()
+            .zip(b)
+            .fold(0, |acc, (&x, &y)| self.reduce_mul_add(x, y, acc))
+    }
+}
+
+impl ReduceInv<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    /// Calculates the inverse by Fermat's little theorem, `value^(p-2)`.
+    #[inline]
+    fn reduce_inv(self, value: u64) -> Self::Output {
+        debug_assert!(value != 0 && value < P);
+        self.reduce_exp(value, P - 2)
+    }
+}
+
+impl ReduceInvAssign<u64> for GoldilocksModulus {
+    #[inline]
+    fn reduce_inv_assign(self, value: &mut u64) {
+        *value = self.reduce_inv(*value);
+    }
+}
+
+impl TryReduceInv<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline]
+    fn try_reduce_inv(self, value: u64) -> Result<Self::Output, AlgebraError> {
+        let value = reduce_once(value);
+        if value == 0 {
+            Err(AlgebraError::NoInverse {
+                value: Box::new(value),
+                modulus: Box::new(P),
+            })
+        } else {
+            Ok(self.reduce_inv(value))
+        }
+    }
+}
+
+impl ReduceDiv<u64> for GoldilocksModulus {
+    type Output = u64;
+
+    #[inline]
+    fn reduce_div(self, a: u64, b: u64) -> Self::Output {
+        self.reduce_mul(a, self.reduce_inv(b))
+    }
+}
+
+impl ReduceDivAssign<u64> for GoldilocksModulus {
+    #[inline]
+    fn reduce_div_assign(self, a: &mut u64, b: u64) {
+        self.reduce_mul_assign(a, self.reduce_inv(b));
+    }
+}
@@ -1,11 +1,17 @@
 //! Defines some moduli.
 
 mod barrett;
+mod goldilocks;
+mod montgomery;
 mod native;
 mod powof2;
 mod shoup;
+#[cfg(feature = "simd")]
+pub(crate) mod simd;
 
 pub use barrett::BarrettModulus;
+pub use goldilocks::GoldilocksModulus;
+pub use montgomery::MontgomeryModulus;
 pub use native::NativeModulus;
 pub use powof2::PowOf2Modulus;
 pub use shoup::ShoupFactor;
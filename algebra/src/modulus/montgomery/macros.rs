@@ -0,0 +1,81 @@
+macro_rules! impl_montgomery_modulus {
+    (impl MontgomeryModulus<$ValueT:ty>; WideType: $WideT:ty) => {
+        impl MontgomeryModulus<$ValueT> {
+            /// Creates a [`MontgomeryModulus<T>`] instance.
+            ///
+            /// - `value`: The value of the modulus.
+            ///
+            /// # Panics
+            ///
+            /// The `value` must be odd and greater than 1. Like
+            /// [`BarrettModulus<T>`](crate::modulus::BarrettModulus), it reserves 2 bits of padding,
+            /// so the reduction of a product never overflows.
+            #[must_use]
+            pub const fn new(value: $ValueT) -> Self {
+                match value {
+                    0 | 1 => panic!("modulus can't be 0 or 1."),
+                    _ => {
+                        assert!(value & 1 == 1, "montgomery modulus must be odd.");
+                        let bit_count = <$ValueT>::BITS - value.leading_zeros();
+                        assert!(bit_count < <$ValueT>::BITS - 1);
+
+                        // Newton's iteration, each step doubles the correct low bits,
+                        // and `value * value ≡ 1 (mod 8)` gives the first 3 bits.
+                        let mut inv = value;
+                        let mut correct_bits = 3;
+                        while correct_bits < <$ValueT>::BITS {
+                            inv = inv
+                                .wrapping_mul((2 as $ValueT).wrapping_sub(value.wrapping_mul(inv)));
+                            correct_bits *= 2;
+                        }
+
+                        let r = ((1 as $WideT) << <$ValueT>::BITS) % (value as $WideT);
+                        let r2 = (r * r) % (value as $WideT);
+
+                        Self {
+                            value,
+                            neg_inv: inv.wrapping_neg(),
+                            r: r as $ValueT,
+                            r2: r2 as $ValueT,
+                        }
+                    }
+                }
+            }
+        }
+
+        impl Modulus<$ValueT> for MontgomeryModulus<$ValueT> {
+            #[inline]
+            fn from_value(value: ModulusValue<$ValueT>) -> Self {
+                match value {
+                    ModulusValue::Native => panic!("Not match for native"),
+                    ModulusValue::PowerOf2(_) => panic!("montgomery modulus must be odd."),
+                    ModulusValue::Prime(value) | ModulusValue::Others(value) => Self::new(value),
+                }
+            }
+
+            #[inline]
+            fn try_from_value(value: ModulusValue<$ValueT>) -> Option<Self> {
+                match value {
+                    ModulusValue::Prime(value) | ModulusValue::Others(value)
+                        if value > 1
+                            && value & 1 == 1
+                            && <$ValueT>::BITS - value.leading_zeros() < <$ValueT>::BITS - 1 =>
+                    {
+                        Some(Self::new(value))
+                    }
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            fn modulus_value(&self) -> ModulusValue<$ValueT> {
+                ModulusValue::Others(self.value)
+            }
+
+            #[inline]
+            fn modulus_minus_one(&self) -> $ValueT {
+                self.value - 1
+            }
+        }
+    };
+}
@@ -0,0 +1,119 @@
+use crate::{
+    numeric::Numeric,
+    reduce::{Modulus, ModulusValue},
+};
+
+#[macro_use]
+mod macros;
+mod ops;
+
+/// A modulus, using montgomery multiplication.
+///
+/// Values are kept in montgomery form `aR mod p`, where `R` = 2^T::BITS.
+/// The product of two values in montgomery form is another value in montgomery form,
+/// which is computed by two multiplications and no division.
+///
+/// Addition, subtraction and negation are the same as the normal form.
+/// Use [`MontgomeryModulus::to_montgomery`] and [`MontgomeryModulus::from_montgomery`]
+/// to convert between the two forms.
+///
+/// Multiplication doesn't branch on its inputs.
+#[derive(Debug, Clone, Copy)]
+pub struct MontgomeryModulus<T: Numeric> {
+    /// the value to indicate the modulus
+    value: T,
+    /// `-p⁻¹ mod R`
+    neg_inv: T,
+    /// `R mod p`, which is `1` in montgomery form
+    r: T,
+    /// `R² mod p`
+    r2: T,
+}
+
+impl<T: Numeric> MontgomeryModulus<T> {
+    /// Returns the value of this [`MontgomeryModulus<T>`].
+    #[inline]
+    pub const fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns `R mod p`, which is `1` in montgomery form.
+    #[inline]
+    pub const fn one(&self) -> T {
+        self.r
+    }
+
+    /// Converts `value` to montgomery form, `value * R mod p`.
+    #[inline]
+    pub fn to_montgomery(self, value: T) -> T {
+        self.mont_mul(value, self.r2)
+    }
+
+    /// Converts `value` from montgomery form, `value * R⁻¹ mod p`.
+    #[inline]
+    pub fn from_montgomery(self, value: T) -> T {
+        self.redc(value, T::ZERO)
+    }
+}
+
+impl_montgomery_modulus!(impl MontgomeryModulus<u32>; WideType: u64);
+impl_montgomery_modulus!(impl MontgomeryModulus<u64>; WideType: u128);
+
+#[cfg(test)]
+mod tests {
+    use rand::{distributions::Uniform, prelude::*};
+
+    use crate::reduce::*;
+
+    use super::*;
+
+    const P: u64 = 4611686018427387847;
+
+    #[test]
+    fn test_montgomery_form() {
+        let mut rng = thread_rng();
+        let modulus = MontgomeryModulus::<u64>::new(P);
+        let dis = Uniform::new(0, P);
+
+        assert_eq!(modulus.from_montgomery(modulus.one()), 1);
+        assert_eq!(modulus.to_montgomery(1), modulus.one());
+
+        for _ in 0..1000 {
+            let a: u64 = rng.sample(dis);
+            let b: u64 = rng.sample(dis);
+
+            let a_m = modulus.to_montgomery(a);
+            let b_m = modulus.to_montgomery(b);
+            assert_eq!(modulus.from_montgomery(a_m), a);
+
+            assert_eq!(
+                modulus.from_montgomery(modulus.reduce_mul(a_m, b_m)),
+                ((a as u128 * b as u128) % P as u128) as u64
+            );
+            assert_eq!(
+                modulus.from_montgomery(modulus.reduce_add(a_m, b_m)),
+                ((a as u128 + b as u128) % P as u128) as u64
+            );
+
+            let lazy = modulus.lazy_reduce_mul(a_m, b_m);
+            assert!(lazy < 2 * P);
+            assert_eq!(lazy % P, modulus.reduce_mul(a_m, b_m));
+
+            if a != 0 {
+                assert_eq!(
+                    modulus.reduce_mul(a_m, modulus.reduce_inv(a_m)),
+                    modulus.one()
+                );
+            }
+        }
+
+        assert_eq!(
+            modulus.reduce_exp(modulus.to_montgomery(7), 0u32),
+            modulus.one()
+        );
+        assert_eq!(
+            modulus.reduce_exp(modulus.to_montgomery(7), P - 1),
+            modulus.one()
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use crate::integer::UnsignedInteger;
+use crate::numeric::Numeric;
+use crate::reduce::*;
+
+use super::MontgomeryModulus;
+
+/// Returns `a` if `condition` is `true`, otherwise returns `b`, without branching.
+#[inline(always)]
+fn select<T: Numeric>(condition: bool, a: T, b: T) -> T {
+    let mask = T::ZERO.wrapping_sub(T::as_from(condition));
+    (a & mask) | (b & !mask)
+}
+
+impl<T: Numeric> MontgomeryModulus<T> {
+    /// Calculates `(hi * R + lo) * R⁻¹ (mod 2*modulus)` for `hi < modulus`.
+    #[inline(always)]
+    pub(super) fn lazy_redc(self, lo: T, hi: T) -> T {
+        let m = lo.wrapping_mul(self.neg_inv);
+        let (mp_lo, mp_hi) = m.widening_mul(self.value);
+        // `lo + mp_lo` is `0` or `R`.
+        let (_, carry) = lo.overflowing_add(mp_lo);
+        hi + mp_hi + T::as_from(carry)
+    }
+
+    /// Calculates `(hi * R + lo) * R⁻¹ (mod modulus)` for `hi < modulus`.
+    #[inline(always)]
+    pub(super) fn redc(self, lo: T, hi: T) -> T {
+        self.reduce_once(self.lazy_redc(lo, hi))
+    }
+
+    /// Calculates `a * b * R⁻¹ (mod modulus)`.
+    #[inline(always)]
+    pub(super) fn mont_mul(self, a: T, b: T) -> T {
+        let (lo, hi) = a.widening_mul(b);
+        self.redc(lo, hi)
+    }
+}
+
+impl<T: Numeric> Reduce<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    /// Calculates `value (mod modulus)`, the form of `value` is unchanged.
+    #[inline]
+    fn reduce(self, value: T) -> Self::Output {
+        value % self.value
+    }
+}
+
+impl<T: Numeric> ReduceAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_assign(self, value: &mut T) {
+        *value = self.reduce(*value);
+    }
+}
+
+impl<T: Numeric> ReduceOnce<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    /// Calculates `value (mod modulus)` for `value < 2*modulus`.
+    #[inline(always)]
+    fn reduce_once(self, value: T) -> Self::Output {
+        let (diff, borrow) = value.overflowing_sub(self.value);
+        select(borrow, value, diff)
+    }
+}
+
+impl<T: Numeric> ReduceOnceAssign<T> for MontgomeryModulus<T> {
+    #[inline(always)]
+    fn reduce_once_assign(self, value: &mut T) {
+        *value = self.reduce_once(*value);
+    }
+}
+
+impl<T: Numeric> LazyReduce<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn lazy_reduce(self, value: T) -> Self::Output {
+        self.reduce(value)
+    }
+}
+
+impl<T: Numeric> LazyReduceAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn lazy_reduce_assign(self, value: &mut T) {
+        *value = self.reduce(*value);
+    }
+}
+
+impl<T: Numeric> ReduceAdd<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_add(self, a: T, b: T) -> Self::Output {
+        self.reduce_once(a + b)
+    }
+}
+
+impl<T: Numeric> ReduceAddAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_add_assign(self, a: &mut T, b: T) {
+        *a = self.reduce_once(*a + b);
+    }
+}
+
+impl<T: Numeric> ReduceDouble<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_double(self, value: T) -> Self::Output {
+        self.reduce_once(value << 1u32)
+    }
+}
+
+impl<T: Numeric> ReduceDoubleAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_double_assign(self, value: &mut T) {
+        *value = self.reduce_once(*value << 1u32);
+    }
+}
+
+impl<T: Numeric> ReduceSub<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_sub(self, a: T, b: T) -> Self::Output {
+        let (diff, borrow) = a.overflowing_sub(b);
+        diff.wrapping_add(select(borrow, self.value, T::ZERO))
+    }
+}
+
+impl<T: Numeric> ReduceSubAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_sub_assign(self, a: &mut T, b: T) {
+        *a = self.reduce_sub(*a, b);
+    }
+}
+
+impl<T: Numeric> ReduceNeg<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_neg(self, value: T) -> Self::Output {
+        self.reduce_sub(T::ZERO, value)
+    }
+}
+
+impl<T: Numeric> ReduceNegAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_neg_assign(self, value: &mut T) {
+        *value = self.reduce_sub(T::ZERO, *value);
+    }
+}
+
+impl<T: Numeric> ReduceMul<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    /// Calculates `a * b * R⁻¹ (mod modulus)`,
+    /// which is the product of two values in montgomery form.
+    #[inline]
+    fn reduce_mul(self, a: T, b: T) -> Self::Output {
+        self.mont_mul(a, b)
+    }
+}
+
+impl<T: Numeric> ReduceMulAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_mul_assign(self, a: &mut T, b: T) {
+        *a = self.mont_mul(*a, b);
+    }
+}
+
+impl<T: Numeric> ReduceSquare<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_square(self, value: T) -> Self::Output {
+        self.mont_mul(value, value)
+    }
+}
+
+impl<T: Numeric> ReduceSquareAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_square_assign(self, value: &mut T) {
+        *value = self.mont_mul(*value, *value);
+    }
+}
+
+impl<T: Numeric> ReduceMulAdd<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_mul_add(self, a: T, b: T, c: T) -> Self::Output {
+        self.reduce_add(self.mont_mul(a, b), c)
+    }
+}
+
+impl<T: Numeric> ReduceMulAddAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_mul_add_assign(self, a: &mut T, b: T, c: T) {
+        *a = self.reduce_add(self.mont_mul(*a, b), c);
+    }
+}
+
+impl<T: Numeric> LazyReduceMul<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn lazy_reduce_mul(self, a: T, b: T) -> Self::Output {
+        let (lo, hi) = a.widening_mul(b);
+        self.lazy_redc(lo, hi)
+    }
+}
+
+impl<T: Numeric> LazyReduceMulAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn lazy_reduce_mul_assign(self, a: &mut T, b: T) {
+        *a = self.lazy_reduce_mul(*a, b);
+    }
+}
+
+impl<T: Numeric> LazyReduceMulAdd<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn lazy_reduce_mul_add(self, a: T, b: T, c: T) -> Self::Output {
+        let (lo, hi) = a.widening_mul(b);
+        self.reduce_once(self.lazy_redc(lo, hi) + c)
+    }
+}
+
+impl<T: Numeric> LazyReduceMulAddAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn lazy_reduce_mul_add_assign(self, a: &mut T, b: T, c: T) {
+        *a = self.lazy_reduce_mul_add(*a, b, c);
+    }
+}
+
+impl<T: Numeric> ReduceExp<T> for MontgomeryModulus<T> {
+    /// Calculates `base^exp` for `base` in montgomery form.
+    #[inline]
+    fn reduce_exp<E: UnsignedInteger>(self, base: T, mut exp: E) -> T {
+        let mut power = base;
+        let mut intermediate = self.r;
+
+        while !exp.is_zero() {
+            if !(exp & E::ONE).is_zero() {
+                intermediate = self.mont_mul(intermediate, power);
+            }
+            power = self.mont_mul(power, power);
+            exp >>= 1;
+        }
+        intermediate
+    }
+}
+
+impl<T: Numeric> ReduceExpPowOf2<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_exp_power_of_2(self, base: T, exp_log: u32) -> T {
+        let mut power = base;
+
+        for _ in 0..exp_log {
+            power = self.mont_mul(power, power);
+        }
+
+        power
+    }
+}
+
+impl<T: Numeric> ReduceDotProduct<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_dot_product(self, a: impl AsRef<[T]>, b: impl AsRef<[T]>) -> Self::Output {
+        let a = a.as_ref();
+        let b = b.as_ref();
+
+        debug_assert_eq!(a.len(), b.len());
+
+        a.iter()
+            .zip(b)
+            .fold(T::ZERO, |acc, (&x, &y)| self.reduce_mul_add(x, y, acc))
+    }
+}
+
+impl<T: Numeric> ReduceInv<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    /// Calculates the inverse of `value` in montgomery form,
+    /// by Fermat's little theorem, `value^(p-2)`. The modulus must be a prime.
+    #[inline]
+    fn reduce_inv(self, value: T) -> Self::Output {
+        debug_assert!(!value.is_zero() && value < self.value);
+        self.reduce_exp(value, self.value - T::TWO)
+    }
+}
+
+impl<T: Numeric> ReduceInvAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_inv_assign(self, value: &mut T) {
+        *value = self.reduce_inv(*value);
+    }
+}
+
+impl<T: Numeric> ReduceDiv<T> for MontgomeryModulus<T> {
+    type Output = T;
+
+    #[inline]
+    fn reduce_div(self, a: T, b: T) -> Self::Output {
+        self.mont_mul(a, self.reduce_inv(b))
+    }
+}
+
+impl<T: Numeric> ReduceDivAssign<T> for MontgomeryModulus<T> {
+    #[inline]
+    fn reduce_div_assign(self, a: &mut T, b: T) {
+        *a = self.mont_mul(*a, self.reduce_inv(b));
+    }
+}
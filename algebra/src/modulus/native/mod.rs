@@ -55,4 +55,12 @@ impl<T: UnsignedInteger> Modulus<T> for NativeModulus<T> {
             _ => panic!("The value is not a native modulus."),
         }
     }
+
+    #[inline]
+    fn try_from_value(value: ModulusValue<T>) -> Option<Self> {
+        match value {
+            ModulusValue::Native => Some(Self::new()),
+            _ => None,
+        }
+    }
 }
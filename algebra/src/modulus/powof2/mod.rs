@@ -43,6 +43,16 @@ impl<T: UnsignedInteger> Modulus<T> for PowOf2Modulus<T> {
         }
     }
 
+    #[inline]
+    fn try_from_value(value: ModulusValue<T>) -> Option<Self> {
+        match value {
+            ModulusValue::PowerOf2(value) if value > T::ONE && value.is_power_of_two() => {
+                Some(Self::from_value(ModulusValue::PowerOf2(value)))
+            }
+            _ => None,
+        }
+    }
+
     #[inline]
     fn modulus_value(&self) -> ModulusValue<T> {
         ModulusValue::PowerOf2(self.value())
@@ -0,0 +1,66 @@
+//! The runtime selection of the SIMD kernels of the moduli.
+
+use core::any::TypeId;
+
+/// A SIMD instruction set with kernels.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Isa {
+    /// `x86_64` with AVX2, eight `u32` lanes.
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+    /// `aarch64` with NEON, four `u32` lanes.
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+}
+
+impl Isa {
+    /// Returns the number of `u32` lanes of a vector.
+    #[inline]
+    pub(crate) fn lanes(self) -> usize {
+        match self {
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2 => 8,
+            #[cfg(target_arch = "aarch64")]
+            Self::Neon => 4,
+        }
+    }
+}
+
+/// Returns the instruction set of this CPU, detected at runtime,
+/// or `None` if there is no kernel for it.
+#[inline]
+pub(crate) fn detect() -> Option<Isa> {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx2") {
+        return Some(Isa::Avx2);
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        return Some(Isa::Neon);
+    }
+
+    None
+}
+
+/// Returns `values` as a `u32` slice if `T` is `u32`.
+#[inline]
+pub(crate) fn as_u32_slice<T: 'static>(values: &[T]) -> Option<&[u32]> {
+    if TypeId::of::<T>() == TypeId::of::<u32>() {
+        // SAFETY: `T` is `u32`.
+        Some(unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), values.len()) })
+    } else {
+        None
+    }
+}
+
+/// Returns `values` as a mutable `u32` slice if `T` is `u32`.
+#[inline]
+pub(crate) fn as_u32_slice_mut<T: 'static>(values: &mut [T]) -> Option<&mut [u32]> {
+    if TypeId::of::<T>() == TypeId::of::<u32>() {
+        // SAFETY: `T` is `u32`.
+        Some(unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len()) })
+    } else {
+        None
+    }
+}
@@ -1,4 +1,13 @@
 //! Defines Number Theory Transform algorithms.
+//!
+//! ## Transform order
+//!
+//! The forward transforms of [`FieldTableWithShoupRoot`] and [`TableWithShoupRoot`] use
+//! Cooley-Tukey butterflies, which multiply by the twiddle factor before the addition and
+//! subtraction. They take coefficients in normal order and leave the evaluations in
+//! bit-reversed order. The inverse transforms use Gentleman-Sande butterflies, which multiply
+//! after the subtraction. They take the evaluations in bit-reversed order and return
+//! coefficients in normal order, so no bit-reversal permutation is needed in between.
 
 use crate::{arith::PrimitiveRoot, reduce::Modulus, AlgebraError};
 
@@ -21,6 +30,10 @@ pub trait NttTable: Sized + Clone + Send + Sync {
 }
 
 /// An abstract for Number Theory Transform.
+///
+/// The order of the evaluations is left to the implementation.
+/// Pointwise operations do not depend on it, as long as the inverse transform takes
+/// the order the forward transform produces.
 pub trait NumberTheoryTransform: NttTable {
     /// Polynomial type with coefficients.
     type CoeffPoly: Clone;
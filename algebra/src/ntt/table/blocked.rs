@@ -0,0 +1,227 @@
+use crate::{
+    modulus::{BarrettModulus, ShoupFactor},
+    ntt::{NttTable, NumberTheoryTransform},
+    reduce::{ReduceMul, ReduceOnce, ReduceOnceAssign},
+    Field, NttField,
+};
+
+use super::{butterfly, FieldTableWithShoupRoot};
+
+/// The log2 of the block length.
+///
+/// A block of `2¹¹` values is 8 KiB for `u32` and 16 KiB for `u64`,
+/// which stays in the L1 cache while all its stages are done.
+const LOG_BLOCK: u32 = 11;
+
+/// The number of butterflies handled together in a stage larger than a block.
+const TILE: usize = 1 << 10;
+
+impl<F> FieldTableWithShoupRoot<F>
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+{
+    /// Cache blocked version of [`NumberTheoryTransform::lazy_transform_slice`].
+    ///
+    /// The stages whose butterflies span more than a block run one by one over tiles,
+    /// then each block runs all the remaining stages at once.
+    /// With the `parallel` feature, tiles and blocks are processed with rayon.
+    ///
+    /// The result is identical to [`NumberTheoryTransform::lazy_transform_slice`].
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - inputs in normal order, outputs in bit-reversed order
+    pub fn lazy_blocked_transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
+        let n = self.n();
+        let log_n = self.log_n();
+        debug_assert_eq!(poly.len(), n);
+
+        if log_n <= LOG_BLOCK {
+            self.lazy_transform_slice(poly);
+            return;
+        }
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+
+        let roots = self.root_powers();
+
+        // The `k`-th chunk of the stage with `m` chunks uses `roots[m + k]`.
+        for log_gap in (LOG_BLOCK..log_n).rev() {
+            let gap = 1usize << log_gap;
+            let m = n >> (log_gap + 1);
+            for (vc, &root) in poly.chunks_exact_mut(gap << 1).zip(&roots[m..]) {
+                let (v0, v1) = vc.split_at_mut(gap);
+                for_each_tile(v0, v1, |t0, t1| {
+                    butterfly::forward(root, t0, t1, modulus_value)
+                });
+            }
+        }
+
+        for_each_block(poly, 1 << LOG_BLOCK, |b, block| {
+            for log_gap in (0..LOG_BLOCK).rev() {
+                let gap = 1usize << log_gap;
+                let m = n >> (log_gap + 1);
+                let offset = m + (b << (LOG_BLOCK - log_gap - 1));
+                for (vc, &root) in block.chunks_exact_mut(gap << 1).zip(&roots[offset..]) {
+                    let (v0, v1) = vc.split_at_mut(gap);
+                    butterfly::forward(root, v0, v1, modulus_value);
+                }
+            }
+        });
+    }
+
+    /// Cache blocked version of [`NumberTheoryTransform::transform_slice`].
+    ///
+    /// The result is identical to [`NumberTheoryTransform::transform_slice`].
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - inputs in normal order, outputs in bit-reversed order
+    pub fn blocked_transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
+        self.lazy_blocked_transform_slice(poly);
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        for_each_block(poly, 1 << LOG_BLOCK, |_, block| {
+            block.iter_mut().for_each(|v| {
+                let r = twice_modulus_value.reduce_once(*v);
+                *v = modulus_value.reduce_once(r);
+            })
+        });
+    }
+
+    /// Cache blocked version of [`NumberTheoryTransform::lazy_inverse_transform_slice`].
+    ///
+    /// Each block first runs all the stages whose butterflies fit in it,
+    /// then the larger stages run one by one over tiles.
+    /// With the `parallel` feature, tiles and blocks are processed with rayon.
+    ///
+    /// The result is identical to [`NumberTheoryTransform::lazy_inverse_transform_slice`].
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - inputs in bit-reversed order, outputs in normal order
+    pub fn lazy_blocked_inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
+        let n = self.n();
+        let log_n = self.log_n();
+        debug_assert_eq!(values.len(), n);
+
+        if log_n <= LOG_BLOCK {
+            self.lazy_inverse_transform_slice(values);
+            return;
+        }
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+
+        let roots = self.inv_root_powers();
+
+        // The stages before the one with gap `g` use `n - n/g` roots,
+        // so its `k`-th chunk uses `roots[1 + n - n/g + k]`.
+        for_each_block(values, 1 << LOG_BLOCK, |b, block| {
+            for log_gap in 0..LOG_BLOCK {
+                let gap = 1usize << log_gap;
+                let offset = 1 + n - (n >> log_gap) + (b << (LOG_BLOCK - log_gap - 1));
+                for (vc, &root) in block.chunks_exact_mut(gap << 1).zip(&roots[offset..]) {
+                    let (v0, v1) = vc.split_at_mut(gap);
+                    butterfly::inverse(root, v0, v1, modulus_value);
+                }
+            }
+        });
+
+        for log_gap in LOG_BLOCK..log_n - 1 {
+            let gap = 1usize << log_gap;
+            let offset = 1 + n - (n >> log_gap);
+            for (vc, &root) in values.chunks_exact_mut(gap << 1).zip(&roots[offset..]) {
+                let (v0, v1) = vc.split_at_mut(gap);
+                for_each_tile(v0, v1, |t0, t1| {
+                    butterfly::inverse(root, t0, t1, modulus_value)
+                });
+            }
+        }
+
+        let gap = n >> 1;
+
+        let scalar = self.inv_n();
+        let scaled_r = modulus_value.reduce_mul(scalar.value(), roots[n - 1]);
+        let scaled_r = ShoupFactor::new(scaled_r, modulus_value);
+
+        let (v0, v1) = values.split_at_mut(gap);
+        for_each_tile(v0, v1, |t0, t1| {
+            butterfly::last_inverse(scalar, scaled_r, t0, t1, modulus_value)
+        });
+    }
+
+    /// Cache blocked version of [`NumberTheoryTransform::inverse_transform_slice`].
+    ///
+    /// The result is identical to [`NumberTheoryTransform::inverse_transform_slice`].
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - inputs in bit-reversed order, outputs in normal order
+    pub fn blocked_inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
+        self.lazy_blocked_inverse_transform_slice(values);
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        for_each_block(values, 1 << LOG_BLOCK, |_, block| {
+            block.iter_mut().for_each(|v| {
+                modulus_value.reduce_once_assign(v);
+            })
+        });
+    }
+}
+
+/// Calls `op` on every `block_len` long block of `values` with the block index.
+#[cfg(feature = "parallel")]
+#[inline]
+fn for_each_block<T, Op>(values: &mut [T], block_len: usize, op: Op)
+where
+    T: Send,
+    Op: Fn(usize, &mut [T]) + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    values
+        .par_chunks_mut(block_len)
+        .enumerate()
+        .for_each(|(b, block)| op(b, block));
+}
+
+/// Calls `op` on every `block_len` long block of `values` with the block index.
+#[cfg(not(feature = "parallel"))]
+#[inline]
+fn for_each_block<T, Op>(values: &mut [T], block_len: usize, op: Op)
+where
+    Op: Fn(usize, &mut [T]),
+{
+    values
+        .chunks_mut(block_len)
+        .enumerate()
+        .for_each(|(b, block)| op(b, block));
+}
+
+/// Calls `op` on every pair of [`TILE`] long tiles of `v0` and `v1`.
+#[cfg(feature = "parallel")]
+#[inline]
+fn for_each_tile<T, Op>(v0: &mut [T], v1: &mut [T], op: Op)
+where
+    T: Send,
+    Op: Fn(&mut [T], &mut [T]) + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    v0.par_chunks_mut(TILE)
+        .zip(v1.par_chunks_mut(TILE))
+        .for_each(|(t0, t1)| op(t0, t1));
+}
+
+/// Calls `op` on every pair of [`TILE`] long tiles of `v0` and `v1`.
+#[cfg(not(feature = "parallel"))]
+#[inline]
+fn for_each_tile<T, Op>(v0: &mut [T], v1: &mut [T], op: Op)
+where
+    Op: Fn(&mut [T], &mut [T]),
+{
+    v0.chunks_mut(TILE)
+        .zip(v1.chunks_mut(TILE))
+        .for_each(|(t0, t1)| op(t0, t1));
+}
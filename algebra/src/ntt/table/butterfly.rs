@@ -0,0 +1,80 @@
+//! The butterflies of [`FieldTableWithShoupRoot`](super::FieldTableWithShoupRoot).
+//!
+//! With the `simd` feature, `u32` values are processed with the kernels of
+//! [`super::simd`] when this CPU has them, the rest one by one.
+
+use crate::{
+    modulus::ShoupFactor,
+    numeric::Numeric,
+    reduce::{LazyReduceMul, ReduceAdd, ReduceOnce},
+};
+
+/// The forward butterflies `(x, y) -> (x + ωy, x - ωy)` of the pairs of `v0` and `v1`,
+/// for `ω = root`. The values are in `[0, 4q)`.
+#[inline]
+pub(super) fn forward<T: Numeric>(
+    root: ShoupFactor<T>,
+    v0: &mut [T],
+    v1: &mut [T],
+    modulus_value: T,
+) {
+    #[cfg(feature = "simd")]
+    let done = super::simd::forward(root, v0, v1, modulus_value);
+    #[cfg(not(feature = "simd"))]
+    let done = 0;
+
+    let twice_modulus_value = modulus_value << 1u32;
+    for (i, j) in core::iter::zip(&mut v0[done..], &mut v1[done..]) {
+        let u = twice_modulus_value.reduce_once(*i);
+        let v = modulus_value.lazy_reduce_mul(root, *j);
+        *i = u + v;
+        *j = u + twice_modulus_value - v;
+    }
+}
+
+/// The inverse butterflies `(x, y) -> (x + y, ω(x - y))` of the pairs of `v0` and `v1`,
+/// for `ω = root`. The values are in `[0, 2q)`.
+#[inline]
+pub(super) fn inverse<T: Numeric>(
+    root: ShoupFactor<T>,
+    v0: &mut [T],
+    v1: &mut [T],
+    modulus_value: T,
+) {
+    #[cfg(feature = "simd")]
+    let done = super::simd::inverse(root, v0, v1, modulus_value);
+    #[cfg(not(feature = "simd"))]
+    let done = 0;
+
+    let twice_modulus_value = modulus_value << 1u32;
+    for (i, j) in core::iter::zip(&mut v0[done..], &mut v1[done..]) {
+        let u = *i;
+        let v = *j;
+        *i = twice_modulus_value.reduce_add(u, v);
+        *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, root);
+    }
+}
+
+/// The last inverse butterflies `(x, y) -> (s(x + y), s'(x - y))` of the pairs of `v0` and `v1`,
+/// for `s = scalar` and `s' = scaled_root`. The values are in `[0, 2q)`.
+#[inline]
+pub(super) fn last_inverse<T: Numeric>(
+    scalar: ShoupFactor<T>,
+    scaled_root: ShoupFactor<T>,
+    v0: &mut [T],
+    v1: &mut [T],
+    modulus_value: T,
+) {
+    #[cfg(feature = "simd")]
+    let done = super::simd::last_inverse(scalar, scaled_root, v0, v1, modulus_value);
+    #[cfg(not(feature = "simd"))]
+    let done = 0;
+
+    let twice_modulus_value = modulus_value << 1u32;
+    for (i, j) in core::iter::zip(&mut v0[done..], &mut v1[done..]) {
+        let u = *i;
+        let v = *j;
+        *i = modulus_value.lazy_reduce_mul(u + v, scalar);
+        *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, scaled_root);
+    }
+}
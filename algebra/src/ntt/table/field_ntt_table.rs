@@ -5,14 +5,13 @@ use crate::{
     modulus::{BarrettModulus, ShoupFactor},
     ntt::{NttTable, NumberTheoryTransform},
     polynomial::{FieldNttPolynomial, FieldPolynomial},
-    reduce::{
-        LazyReduceMul, Modulus, ReduceAdd, ReduceInv, ReduceMul, ReduceMulAssign, ReduceOnce,
-        ReduceOnceAssign,
-    },
+    reduce::{Modulus, ReduceInv, ReduceMul, ReduceMulAssign, ReduceOnce, ReduceOnceAssign},
     utils::ReverseLsbs,
     AlgebraError, Field, NttField,
 };
 
+use super::butterfly;
+
 /// This struct store the pre-computed data for number theory transform and
 /// inverse number theory transform.
 ///
@@ -35,6 +34,8 @@ use crate::{
 /// scrambled order:     0  1  5  3  7  2  6  4
 ///                         ----------  ----  -
 /// ```
+///
+/// The order of the transforms is described in the [module documentation](crate::ntt#transform-order).
 pub struct FieldTableWithShoupRoot<F>
 where
     F: NttField,
@@ -245,7 +246,6 @@ where
         debug_assert_eq!(poly.len(), self.n);
 
         let modulus_value = <F as Field>::MODULUS_VALUE;
-        let twice_modulus_value = modulus_value << 1u32;
 
         let roots = self.root_powers();
         let mut root_iter = roots[1..].iter().copied();
@@ -254,12 +254,7 @@ where
             for vc in poly.chunks_exact_mut(gap << 1) {
                 let root = root_iter.next().unwrap();
                 let (v0, v1) = vc.split_at_mut(gap);
-                for (i, j) in core::iter::zip(v0, v1) {
-                    let u = twice_modulus_value.reduce_once(*i);
-                    let v = modulus_value.lazy_reduce_mul(root, *j);
-                    *i = u + v;
-                    *j = u + twice_modulus_value - v;
-                }
+                butterfly::forward(root, v0, v1, modulus_value);
             }
         }
     }
@@ -283,7 +278,6 @@ where
         let log_n = self.log_n;
 
         let modulus_value = <F as Field>::MODULUS_VALUE;
-        let twice_modulus_value = modulus_value << 1u32;
 
         let roots = self.inv_root_powers();
         let mut root_iter = roots[1..].iter().copied();
@@ -292,12 +286,7 @@ where
             for vc in values.chunks_exact_mut(gap << 1) {
                 let root = root_iter.next().unwrap();
                 let (v0, v1) = vc.split_at_mut(gap);
-                for (i, j) in core::iter::zip(v0, v1) {
-                    let u = *i;
-                    let v = *j;
-                    *i = twice_modulus_value.reduce_add(u, v);
-                    *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, root);
-                }
+                butterfly::inverse(root, v0, v1, modulus_value);
             }
         }
 
@@ -308,12 +297,7 @@ where
         let scaled_r = ShoupFactor::new(scaled_r, modulus_value);
 
         let (v0, v1) = values.split_at_mut(gap);
-        for (i, j) in core::iter::zip(v0, v1) {
-            let u = *i;
-            let v = *j;
-            *i = modulus_value.lazy_reduce_mul(u + v, scalar);
-            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, scaled_r);
-        }
+        butterfly::last_inverse(scalar, scaled_r, v0, v1, modulus_value);
     }
 
     #[inline]
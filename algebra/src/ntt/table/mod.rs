@@ -1,7 +1,11 @@
+mod blocked;
+mod butterfly;
 #[cfg(feature = "concrete-ntt")]
 mod concrete;
 mod field_ntt_table;
 mod numeric_ntt_table;
+#[cfg(feature = "simd")]
+mod simd;
 
 #[cfg(feature = "concrete-ntt")]
 pub use concrete::prime32::Concrete32Table;
@@ -30,6 +30,8 @@ use crate::{utils::ReverseLsbs, AlgebraError};
 /// scrambled order:     0  1  5  3  7  2  6  4
 ///                         ----------  ----  -
 /// ```
+///
+/// The order of the transforms is described in the [module documentation](crate::ntt#transform-order).
 #[derive(Clone)]
 pub struct TableWithShoupRoot<T: Numeric> {
     root: T,
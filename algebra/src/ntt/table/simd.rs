@@ -0,0 +1,443 @@
+//! The SIMD kernels of the butterflies for `u32` values.
+//!
+//! Each kernel processes the whole vectors of the pairs and returns how many
+//! pairs it has done, the caller does the rest one by one. They compute the same
+//! wrapping `u32` arithmetic as the scalar butterflies, so the results are identical.
+//! The lazy Shoup product `ωy - ⌊ω'y / 2³²⌋q` is in `[0, 2q)`, where `ω' = ⌊ω2³² / q⌋`.
+
+use crate::{
+    integer::AsInto,
+    modulus::{
+        simd::{as_u32_slice_mut, detect, Isa},
+        ShoupFactor,
+    },
+    numeric::Numeric,
+};
+
+/// A [`ShoupFactor`] as `u32` lanes.
+#[derive(Clone, Copy)]
+struct Factor {
+    value: u32,
+    quotient: u32,
+}
+
+impl Factor {
+    #[inline]
+    fn new<T: Numeric>(factor: ShoupFactor<T>) -> Self {
+        Self {
+            value: factor.value().as_into(),
+            quotient: factor.quotient().as_into(),
+        }
+    }
+}
+
+/// Returns the instruction set and `v0` and `v1` as `u32` slices, if they have a kernel
+/// and at least a whole vector.
+#[inline]
+fn lanes<'a, T: Numeric>(
+    v0: &'a mut [T],
+    v1: &'a mut [T],
+) -> Option<(Isa, &'a mut [u32], &'a mut [u32])> {
+    let isa = detect()?;
+    if v0.len() < isa.lanes() {
+        return None;
+    }
+    Some((isa, as_u32_slice_mut(v0)?, as_u32_slice_mut(v1)?))
+}
+
+/// The SIMD part of [`butterfly::forward`](super::butterfly::forward).
+#[inline]
+pub(super) fn forward<T: Numeric>(
+    root: ShoupFactor<T>,
+    v0: &mut [T],
+    v1: &mut [T],
+    modulus_value: T,
+) -> usize {
+    let Some((isa, v0, v1)) = lanes(v0, v1) else {
+        return 0;
+    };
+    let root = Factor::new(root);
+    let q: u32 = modulus_value.as_into();
+
+    match isa {
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: AVX2 is available on this CPU.
+        Isa::Avx2 => unsafe { avx2::forward(root, v0, v1, q) },
+        #[cfg(target_arch = "aarch64")]
+        // SAFETY: NEON is available on this CPU.
+        Isa::Neon => unsafe { neon::forward(root, v0, v1, q) },
+    }
+}
+
+/// The SIMD part of [`butterfly::inverse`](super::butterfly::inverse).
+#[inline]
+pub(super) fn inverse<T: Numeric>(
+    root: ShoupFactor<T>,
+    v0: &mut [T],
+    v1: &mut [T],
+    modulus_value: T,
+) -> usize {
+    let Some((isa, v0, v1)) = lanes(v0, v1) else {
+        return 0;
+    };
+    let root = Factor::new(root);
+    let q: u32 = modulus_value.as_into();
+
+    match isa {
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: AVX2 is available on this CPU.
+        Isa::Avx2 => unsafe { avx2::inverse(root, v0, v1, q) },
+        #[cfg(target_arch = "aarch64")]
+        // SAFETY: NEON is available on this CPU.
+        Isa::Neon => unsafe { neon::inverse(root, v0, v1, q) },
+    }
+}
+
+/// The SIMD part of [`butterfly::last_inverse`](super::butterfly::last_inverse).
+#[inline]
+pub(super) fn last_inverse<T: Numeric>(
+    scalar: ShoupFactor<T>,
+    scaled_root: ShoupFactor<T>,
+    v0: &mut [T],
+    v1: &mut [T],
+    modulus_value: T,
+) -> usize {
+    let Some((isa, v0, v1)) = lanes(v0, v1) else {
+        return 0;
+    };
+    let scalar = Factor::new(scalar);
+    let scaled_root = Factor::new(scaled_root);
+    let q: u32 = modulus_value.as_into();
+
+    match isa {
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: AVX2 is available on this CPU.
+        Isa::Avx2 => unsafe { avx2::last_inverse(scalar, scaled_root, v0, v1, q) },
+        #[cfg(target_arch = "aarch64")]
+        // SAFETY: NEON is available on this CPU.
+        Isa::Neon => unsafe { neon::last_inverse(scalar, scaled_root, v0, v1, q) },
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use core::arch::x86_64::*;
+
+    use super::Factor;
+
+    /// A [`Factor`] broadcast to eight lanes.
+    #[derive(Clone, Copy)]
+    struct VFactor {
+        value: __m256i,
+        quotient: __m256i,
+    }
+
+    impl VFactor {
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        unsafe fn new(factor: Factor) -> Self {
+            Self {
+                value: _mm256_set1_epi32(factor.value as i32),
+                quotient: _mm256_set1_epi32(factor.quotient as i32),
+            }
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn forward(root: Factor, v0: &mut [u32], v1: &mut [u32], q: u32) -> usize {
+        let root = VFactor::new(root);
+        let vq = _mm256_set1_epi32(q as i32);
+        let vq2 = _mm256_set1_epi32((q << 1) as i32);
+
+        let mut done = 0;
+        for (i, j) in v0.chunks_exact_mut(8).zip(v1.chunks_exact_mut(8)) {
+            let u = reduce_once(load(i), vq2);
+            let v = lazy_mul(load(j), root, vq);
+            store(i, _mm256_add_epi32(u, v));
+            store(j, _mm256_sub_epi32(_mm256_add_epi32(u, vq2), v));
+            done += 8;
+        }
+        done
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn inverse(root: Factor, v0: &mut [u32], v1: &mut [u32], q: u32) -> usize {
+        let root = VFactor::new(root);
+        let vq = _mm256_set1_epi32(q as i32);
+        let vq2 = _mm256_set1_epi32((q << 1) as i32);
+
+        let mut done = 0;
+        for (i, j) in v0.chunks_exact_mut(8).zip(v1.chunks_exact_mut(8)) {
+            let (x, y) = (load(i), load(j));
+            let diff = _mm256_sub_epi32(_mm256_add_epi32(x, vq2), y);
+            store(i, reduce_once(_mm256_add_epi32(x, y), vq2));
+            store(j, lazy_mul(diff, root, vq));
+            done += 8;
+        }
+        done
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn last_inverse(
+        scalar: Factor,
+        scaled_root: Factor,
+        v0: &mut [u32],
+        v1: &mut [u32],
+        q: u32,
+    ) -> usize {
+        let scalar = VFactor::new(scalar);
+        let scaled_root = VFactor::new(scaled_root);
+        let vq = _mm256_set1_epi32(q as i32);
+        let vq2 = _mm256_set1_epi32((q << 1) as i32);
+
+        let mut done = 0;
+        for (i, j) in v0.chunks_exact_mut(8).zip(v1.chunks_exact_mut(8)) {
+            let (x, y) = (load(i), load(j));
+            let diff = _mm256_sub_epi32(_mm256_add_epi32(x, vq2), y);
+            store(i, lazy_mul(_mm256_add_epi32(x, y), scalar, vq));
+            store(j, lazy_mul(diff, scaled_root, vq));
+            done += 8;
+        }
+        done
+    }
+
+    /// Loads the first eight values of `values`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn load(values: &[u32]) -> __m256i {
+        debug_assert!(values.len() >= 8);
+        _mm256_loadu_si256(values.as_ptr() as *const __m256i)
+    }
+
+    /// Stores `x` to the first eight values of `values`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn store(values: &mut [u32], x: __m256i) {
+        debug_assert!(values.len() >= 8);
+        _mm256_storeu_si256(values.as_mut_ptr() as *mut __m256i, x)
+    }
+
+    /// Calculates `x mod m` for `x < 2m`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn reduce_once(x: __m256i, m: __m256i) -> __m256i {
+        // if `x < m` then `x - m` wraps around and is larger than `x`.
+        _mm256_min_epu32(x, _mm256_sub_epi32(x, m))
+    }
+
+    /// The lazy Shoup product of `y` and `factor`, in `[0, 2q)`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn lazy_mul(y: __m256i, factor: VFactor, q: __m256i) -> __m256i {
+        let hw = mul_hi(factor.quotient, y);
+        _mm256_sub_epi32(
+            _mm256_mullo_epi32(factor.value, y),
+            _mm256_mullo_epi32(hw, q),
+        )
+    }
+
+    /// Calculates `⌊x * y / 2³²⌋` for eight `u32` lanes.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul_hi(x: __m256i, y: __m256i) -> __m256i {
+        let even = _mm256_srli_epi64::<32>(_mm256_mul_epu32(x, y));
+        let odd = _mm256_mul_epu32(_mm256_srli_epi64::<32>(x), _mm256_srli_epi64::<32>(y));
+        _mm256_blend_epi32::<0b1010_1010>(even, odd)
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use core::arch::aarch64::*;
+
+    use super::Factor;
+
+    /// A [`Factor`] broadcast to four lanes.
+    #[derive(Clone, Copy)]
+    struct VFactor {
+        value: uint32x4_t,
+        quotient: uint32x4_t,
+    }
+
+    impl VFactor {
+        #[inline]
+        #[target_feature(enable = "neon")]
+        unsafe fn new(factor: Factor) -> Self {
+            Self {
+                value: vdupq_n_u32(factor.value),
+                quotient: vdupq_n_u32(factor.quotient),
+            }
+        }
+    }
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn forward(root: Factor, v0: &mut [u32], v1: &mut [u32], q: u32) -> usize {
+        let root = VFactor::new(root);
+        let vq = vdupq_n_u32(q);
+        let vq2 = vdupq_n_u32(q << 1);
+
+        let mut done = 0;
+        for (i, j) in v0.chunks_exact_mut(4).zip(v1.chunks_exact_mut(4)) {
+            let u = reduce_once(vld1q_u32(i.as_ptr()), vq2);
+            let v = lazy_mul(vld1q_u32(j.as_ptr()), root, vq);
+            vst1q_u32(i.as_mut_ptr(), vaddq_u32(u, v));
+            vst1q_u32(j.as_mut_ptr(), vsubq_u32(vaddq_u32(u, vq2), v));
+            done += 4;
+        }
+        done
+    }
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn inverse(root: Factor, v0: &mut [u32], v1: &mut [u32], q: u32) -> usize {
+        let root = VFactor::new(root);
+        let vq = vdupq_n_u32(q);
+        let vq2 = vdupq_n_u32(q << 1);
+
+        let mut done = 0;
+        for (i, j) in v0.chunks_exact_mut(4).zip(v1.chunks_exact_mut(4)) {
+            let (x, y) = (vld1q_u32(i.as_ptr()), vld1q_u32(j.as_ptr()));
+            let diff = vsubq_u32(vaddq_u32(x, vq2), y);
+            vst1q_u32(i.as_mut_ptr(), reduce_once(vaddq_u32(x, y), vq2));
+            vst1q_u32(j.as_mut_ptr(), lazy_mul(diff, root, vq));
+            done += 4;
+        }
+        done
+    }
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn last_inverse(
+        scalar: Factor,
+        scaled_root: Factor,
+        v0: &mut [u32],
+        v1: &mut [u32],
+        q: u32,
+    ) -> usize {
+        let scalar = VFactor::new(scalar);
+        let scaled_root = VFactor::new(scaled_root);
+        let vq = vdupq_n_u32(q);
+        let vq2 = vdupq_n_u32(q << 1);
+
+        let mut done = 0;
+        for (i, j) in v0.chunks_exact_mut(4).zip(v1.chunks_exact_mut(4)) {
+            let (x, y) = (vld1q_u32(i.as_ptr()), vld1q_u32(j.as_ptr()));
+            let diff = vsubq_u32(vaddq_u32(x, vq2), y);
+            vst1q_u32(i.as_mut_ptr(), lazy_mul(vaddq_u32(x, y), scalar, vq));
+            vst1q_u32(j.as_mut_ptr(), lazy_mul(diff, scaled_root, vq));
+            done += 4;
+        }
+        done
+    }
+
+    /// Calculates `x mod m` for `x < 2m`.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn reduce_once(x: uint32x4_t, m: uint32x4_t) -> uint32x4_t {
+        // if `x < m` then `x - m` wraps around and is larger than `x`.
+        vminq_u32(x, vsubq_u32(x, m))
+    }
+
+    /// The lazy Shoup product of `y` and `factor`, in `[0, 2q)`.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn lazy_mul(y: uint32x4_t, factor: VFactor, q: uint32x4_t) -> uint32x4_t {
+        let hw = mul_hi(factor.quotient, y);
+        vsubq_u32(vmulq_u32(factor.value, y), vmulq_u32(hw, q))
+    }
+
+    /// Calculates `⌊x * y / 2³²⌋` for four `u32` lanes.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn mul_hi(x: uint32x4_t, y: uint32x4_t) -> uint32x4_t {
+        let lo = vreinterpretq_u32_u64(vmull_u32(vget_low_u32(x), vget_low_u32(y)));
+        let hi = vreinterpretq_u32_u64(vmull_high_u32(x, y));
+        vuzp2q_u32(lo, hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{distributions::Uniform, prelude::*};
+
+    use crate::reduce::{LazyReduceMul, ReduceAdd, ReduceMul, ReduceOnce};
+
+    use super::*;
+
+    const LEN: usize = 67;
+
+    fn factor(rng: &mut ThreadRng, q: u32) -> ShoupFactor<u32> {
+        ShoupFactor::new(rng.gen_range(0..q), q)
+    }
+
+    fn sample(rng: &mut ThreadRng, bound: u32) -> Vec<u32> {
+        Uniform::new(0, bound).sample_iter(rng).take(LEN).collect()
+    }
+
+    /// Checks the kernel done pairs, the others are left as they were.
+    fn check(
+        done: usize,
+        (x, y): (&[u32], &[u32]),
+        (v0, v1): (&[u32], &[u32]),
+        op: impl Fn(u32, u32) -> (u32, u32),
+    ) {
+        assert_eq!(
+            done,
+            detect().map_or(0, |isa| LEN / isa.lanes() * isa.lanes())
+        );
+        for k in 0..LEN {
+            let expected = if k < done {
+                op(x[k], y[k])
+            } else {
+                (x[k], y[k])
+            };
+            assert_eq!((v0[k], v1[k]), expected, "pair {k}");
+        }
+    }
+
+    #[test]
+    fn test_simd_butterflies() {
+        let mut rng = thread_rng();
+
+        for q in [3u32, 12289, 132120577, (1 << 30) - 35] {
+            let twice_q = q << 1;
+
+            let root = factor(&mut rng, q);
+            let x = sample(&mut rng, q << 2);
+            let y = sample(&mut rng, q << 2);
+            let (mut v0, mut v1) = (x.clone(), y.clone());
+            let done = forward(root, &mut v0, &mut v1, q);
+            check(done, (&x, &y), (&v0, &v1), |u, v| {
+                let u = twice_q.reduce_once(u);
+                let v = q.lazy_reduce_mul(root, v);
+                (u + v, u + twice_q - v)
+            });
+            for (u, v) in v0[..done].iter().zip(&v1[..done]) {
+                assert!(*u < q << 2 && *v < q << 2);
+            }
+
+            let x = sample(&mut rng, twice_q);
+            let y = sample(&mut rng, twice_q);
+            let (mut v0, mut v1) = (x.clone(), y.clone());
+            let done = inverse(root, &mut v0, &mut v1, q);
+            check(done, (&x, &y), (&v0, &v1), |u, v| {
+                (
+                    twice_q.reduce_add(u, v),
+                    q.lazy_reduce_mul(u + twice_q - v, root),
+                )
+            });
+
+            let scalar = factor(&mut rng, q);
+            let scaled_root = ShoupFactor::new(q.reduce_mul(scalar.value(), root), q);
+            let (mut v0, mut v1) = (x.clone(), y.clone());
+            let done = last_inverse(scalar, scaled_root, &mut v0, &mut v1, q);
+            check(done, (&x, &y), (&v0, &v1), |u, v| {
+                (
+                    q.lazy_reduce_mul(u + v, scalar),
+                    q.lazy_reduce_mul(u + twice_q - v, scaled_root),
+                )
+            });
+            for (u, v) in v0[..done].iter().zip(&v1[..done]) {
+                assert!(*u < twice_q && *v < twice_q);
+            }
+        }
+    }
+}
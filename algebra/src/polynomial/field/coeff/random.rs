@@ -1,4 +1,4 @@
-use num_traits::ConstZero;
+use num_traits::{ConstOne, ConstZero};
 use rand::{CryptoRng, Rng};
 use rand_distr::{Distribution, Uniform};
 
@@ -11,9 +11,9 @@ impl<F: Field> FieldPolynomial<F> {
     #[must_use]
     #[inline]
     pub fn uniform_distribution() -> Uniform<<F as Field>::ValueT> {
-        Uniform::new_inclusive(
+        Uniform::new(
             <<F as Field>::ValueT as ConstZero>::ZERO,
-            <F as Field>::MINUS_ONE,
+            <F as Field>::MODULUS_VALUE,
         )
     }
 
@@ -33,7 +33,9 @@ impl<F: Field> FieldPolynomial<F> {
     where
         R: Rng + CryptoRng,
     {
-        Self::new(crate::random::sample_binary_values(n, rng))
+        let mut values = crate::random::sample_binary_values(n, rng);
+        values.iter_mut().for_each(|v| *v = F::from_canonical(*v));
+        Self::new(values)
     }
 
     /// Generate a random ternary [`FieldPolynomial<F>`].
@@ -42,14 +44,19 @@ impl<F: Field> FieldPolynomial<F> {
     where
         R: Rng + CryptoRng,
     {
-        Self::new(crate::random::sample_ternary_values(
-            <F as Field>::MINUS_ONE,
+        let mut values = crate::random::sample_ternary_values(
+            <F as Field>::MODULUS_VALUE - <<F as Field>::ValueT as ConstOne>::ONE,
             n,
             rng,
-        ))
+        );
+        values.iter_mut().for_each(|v| *v = F::from_canonical(*v));
+        Self::new(values)
     }
 
     /// Generate a random [`FieldPolynomial<F>`] with discrete gaussian distribution.
+    ///
+    /// The `gaussian` samples canonical values, so its modulus minus one should be
+    /// `F::MODULUS_VALUE - 1`. They are converted by [`Field::from_canonical`].
     #[inline]
     pub fn random_gaussian<R>(
         n: usize,
@@ -59,7 +66,26 @@ impl<F: Field> FieldPolynomial<F> {
     where
         R: Rng + CryptoRng,
     {
-        Self::new(gaussian.sample_iter(rng).take(n).collect())
+        Self::random_noise(n, gaussian, rng)
+    }
+
+    /// Generate a random [`FieldPolynomial<F>`] with a noise distribution,
+    /// such as [`DiscreteGaussian`] or a ternary sampler.
+    ///
+    /// The `noise` samples canonical values, they are converted by [`Field::from_canonical`].
+    #[inline]
+    pub fn random_noise<R, D>(n: usize, noise: D, rng: &mut R) -> Self
+    where
+        R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT>,
+    {
+        Self::new(
+            noise
+                .sample_iter(rng)
+                .take(n)
+                .map(F::from_canonical)
+                .collect(),
+        )
     }
 
     /// Generate a random [`FieldPolynomial<F>`].
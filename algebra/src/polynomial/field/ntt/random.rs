@@ -11,9 +11,9 @@ impl<F: NttField> FieldNttPolynomial<F> {
     #[must_use]
     #[inline]
     pub fn uniform_distribution() -> Uniform<<F as Field>::ValueT> {
-        Uniform::new_inclusive(
+        Uniform::new(
             <<F as Field>::ValueT as ConstZero>::ZERO,
-            <F as Field>::MINUS_ONE,
+            <F as Field>::MODULUS_VALUE,
         )
     }
 
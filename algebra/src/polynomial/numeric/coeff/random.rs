@@ -56,7 +56,18 @@ impl<T: UnsignedInteger> Polynomial<T> {
     where
         R: Rng + CryptoRng,
     {
-        Self::new(gaussian.sample_iter(rng).take(coeff_count).collect())
+        Self::random_noise(gaussian, coeff_count, rng)
+    }
+
+    /// Generate a random [`Polynomial<T>`] with a noise distribution,
+    /// such as [`DiscreteGaussian`] or a ternary sampler.
+    #[inline]
+    pub fn random_noise<R, D>(noise: D, coeff_count: usize, rng: &mut R) -> Self
+    where
+        R: Rng + CryptoRng,
+        D: Distribution<T>,
+    {
+        Self::new(noise.sample_iter(rng).take(coeff_count).collect())
     }
 
     /// Generate a random [`Polynomial<T>`].
@@ -35,24 +35,54 @@ where
     T: UnsignedInteger,
     R: Rng + CryptoRng,
 {
-    let s = [T::ZERO, T::ZERO, T::ONE, minus_one];
     let mut v = vec![T::ZERO; length];
     let mut iter = v.chunks_exact_mut(16);
     for chunk in &mut iter {
         let mut r = rng.next_u32();
         for elem in chunk.iter_mut() {
-            *elem = s[(r & 0b11) as usize];
+            *elem = ternary_from_bits(r, minus_one);
             r >>= 2;
         }
     }
     let mut r = rng.next_u32();
     for elem in iter.into_remainder() {
-        *elem = s[(r & 0b11) as usize];
+        *elem = ternary_from_bits(r, minus_one);
         r >>= 2;
     }
     v
 }
 
+/// Maps the two lowest bits of `r` to `0, 0, 1, -1` without branches or table lookups.
+///
+/// The higher bit decides whether the value is zero, the lower bit decides the sign.
+#[inline(always)]
+fn ternary_from_bits<T: Integer>(r: u32, minus_one: T) -> T {
+    let non_zero = T::ZERO.wrapping_sub(T::as_from((r >> 1) & 0b1));
+    let negative = T::ZERO.wrapping_sub(T::as_from(r & 0b1));
+    ((minus_one & negative) | (T::ONE & !negative)) & non_zero
+}
+
+/// A distribution of secret key or noise values.
+///
+/// Negative values are represented modulo the modulus, as `q - |x|`.
+/// The mean and the variance are those of the represented integers.
+pub trait NoiseDistribution<T>: Distribution<T> {
+    /// Returns the mean of the distribution.
+    fn mean(&self) -> f64;
+
+    /// Returns the variance of the distribution.
+    fn variance(&self) -> f64;
+
+    /// Samples `length` values of the distribution.
+    #[inline]
+    fn sample_values<R>(&self, length: usize, rng: &mut R) -> Vec<T>
+    where
+        R: Rng + CryptoRng,
+    {
+        self.sample_iter(rng).take(length).collect()
+    }
+}
+
 /// The gaussian distribution `N(mean, std_dev**2)`.
 #[derive(Clone, Copy, Debug)]
 pub struct DiscreteGaussian<T: UnsignedInteger> {
@@ -151,6 +181,20 @@ impl<T: UnsignedInteger> Distribution<T> for DiscreteGaussian<T> {
     }
 }
 
+impl<T: UnsignedInteger> NoiseDistribution<T> for DiscreteGaussian<T> {
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.normal.mean()
+    }
+
+    /// Returns `σ²`, ignoring the rounding and the tail cut at [`Self::max_std_dev`].
+    #[inline]
+    fn variance(&self) -> f64 {
+        let std_dev = self.normal.std_dev();
+        std_dev * std_dev
+    }
+}
+
 /// The binary sampler.
 ///
 /// prob\[1] = prob\[0] = 0.5
@@ -164,6 +208,18 @@ impl<T: Integer> Distribution<T> for BinarySampler {
     }
 }
 
+impl<T: Integer> NoiseDistribution<T> for BinarySampler {
+    #[inline]
+    fn mean(&self) -> f64 {
+        0.5
+    }
+
+    #[inline]
+    fn variance(&self) -> f64 {
+        0.25
+    }
+}
+
 /// The ternary sampler.
 ///
 /// prob\[1] = prob\[-1] = 0.25
@@ -180,11 +236,29 @@ impl<T: Integer> TernarySampler<T> {
     pub fn new(minus_one: T) -> Self {
         Self { minus_one }
     }
+
+    /// Returns the value used for `-1`.
+    #[inline]
+    pub fn minus_one(&self) -> T {
+        self.minus_one
+    }
 }
 
 impl<T: Integer> Distribution<T> for TernarySampler<T> {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
-        [T::ZERO, T::ZERO, T::ONE, self.minus_one][(rng.next_u32() & 0b11) as usize]
+        ternary_from_bits(rng.next_u32(), self.minus_one)
+    }
+}
+
+impl<T: Integer> NoiseDistribution<T> for TernarySampler<T> {
+    #[inline]
+    fn mean(&self) -> f64 {
+        0.0
+    }
+
+    #[inline]
+    fn variance(&self) -> f64 {
+        0.5
     }
 }
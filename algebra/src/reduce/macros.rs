@@ -10,6 +10,16 @@ macro_rules! impl_reduce_ops_for_primitive {
                 }
             }
 
+            #[inline(always)]
+            fn try_from_value(value: $crate::reduce::ModulusValue<$ValueT>) -> Option<Self> {
+                match value {
+                    $crate::reduce::ModulusValue::Native => None,
+                    $crate::reduce::ModulusValue::PowerOf2(value)
+                    | $crate::reduce::ModulusValue::Prime(value)
+                    | $crate::reduce::ModulusValue::Others(value) => Some(value),
+                }
+            }
+
             #[inline(always)]
             fn modulus_value(&self) -> $crate::reduce::ModulusValue<$ValueT> {
                 $crate::reduce::ModulusValue::Others(*self)
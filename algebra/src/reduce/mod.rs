@@ -19,6 +19,7 @@ use crate::{integer::UnsignedInteger, numeric::Numeric};
 ///
 /// * `C` - An unsigned integer type that represents the coefficients.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ModulusValue<C> {
     /// Native modulus.
     Native,
@@ -89,6 +90,19 @@ pub trait Modulus<T> {
     /// Converts a modulus value to a modulus.
     fn from_value(value: ModulusValue<T>) -> Self;
 
+    /// Converts a modulus value to a modulus,
+    /// or returns `None` where [`Modulus::from_value`] would panic.
+    ///
+    /// The default calls [`Modulus::from_value`] and never returns `None`.
+    /// Implementors whose [`Modulus::from_value`] can panic should override it.
+    #[inline]
+    fn try_from_value(value: ModulusValue<T>) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Some(Self::from_value(value))
+    }
+
     /// Returns the modulus value.
     fn modulus_value(&self) -> ModulusValue<T>;
 
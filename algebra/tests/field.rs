@@ -1,4 +1,7 @@
-use algebra::{decompose::NonPowOf2ApproxSignedBasis, reduce::ReduceExp, Field, U32FieldEval};
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis, reduce::ReduceExp, Choice, DynField, ErasedField, Field,
+    U16FieldEval, U32FieldEval, U32MontgomeryField, U64FieldEval, U64MontgomeryField, U8FieldEval,
+};
 use rand::{distributions::Uniform, thread_rng, Rng};
 
 type FF = U32FieldEval<132120577>;
@@ -132,3 +135,235 @@ fn test_decompose() {
         None => assert_eq!(compose, a),
     };
 }
+
+#[test]
+fn test_batch_inv() {
+    let distr = Uniform::new(0, FF::MODULUS_VALUE);
+    let mut rng = thread_rng();
+
+    let mut values: Vec<ValueT> = (&mut rng).sample_iter(distr).take(1000).collect();
+    values[0] = FF::ZERO;
+    values[500] = FF::ZERO;
+    let expected: Vec<ValueT> = values
+        .iter()
+        .map(|&v| if v == FF::ZERO { FF::ZERO } else { FF::inv(v) })
+        .collect();
+
+    FF::batch_inv_assign(&mut values);
+    assert_eq!(values, expected);
+
+    let mut empty: Vec<ValueT> = Vec::new();
+    FF::batch_inv_assign(&mut empty);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_montgomery_field() {
+    type MF = U32MontgomeryField<132120577>;
+
+    let distr = Uniform::new(0, FF::MODULUS_VALUE);
+    let mut rng = thread_rng();
+
+    let to = MF::from_canonical;
+    let from = MF::to_canonical;
+
+    assert_eq!(from(MF::ZERO), FF::ZERO);
+    assert_eq!(from(MF::ONE), FF::ONE);
+    assert_eq!(from(MF::MINUS_ONE), FF::MINUS_ONE);
+
+    for _ in 0..1000 {
+        let a = rng.sample(distr);
+        let b = rng.sample(distr);
+        let c = rng.sample(distr);
+
+        assert_eq!(from(to(a)), a);
+        assert_eq!(from(MF::add(to(a), to(b))), FF::add(a, b));
+        assert_eq!(from(MF::sub(to(a), to(b))), FF::sub(a, b));
+        assert_eq!(from(MF::neg(to(a))), FF::neg(a));
+        assert_eq!(from(MF::mul(to(a), to(b))), FF::mul(a, b));
+        assert_eq!(from(MF::mul_add(to(a), to(b), to(c))), FF::mul_add(a, b, c));
+        assert_eq!(from(MF::exp(to(a), c)), FF::exp(a, c));
+        if b != 0 {
+            assert_eq!(from(MF::inv(to(b))), FF::inv(b));
+            assert_eq!(from(MF::div(to(a), to(b))), FF::div(a, b));
+        }
+    }
+
+    let a: Vec<ValueT> = (&mut rng).sample_iter(distr).take(256).collect();
+    let b: Vec<ValueT> = (&mut rng).sample_iter(distr).take(256).collect();
+    let a_m: Vec<ValueT> = a.iter().copied().map(to).collect();
+    let b_m: Vec<ValueT> = b.iter().copied().map(to).collect();
+    assert_eq!(from(MF::dot_product(&a_m, &b_m)), FF::dot_product(&a, &b));
+}
+
+#[test]
+fn test_montgomery_field_u64() {
+    const P: u64 = 4611686018427387847;
+    type MF = U64MontgomeryField<P>;
+    type BF = U64FieldEval<P>;
+
+    let distr = Uniform::new(0, P);
+    let mut rng = thread_rng();
+
+    let to = MF::from_canonical;
+    let from = MF::to_canonical;
+
+    for _ in 0..1000 {
+        let a = rng.sample(distr);
+        let b = rng.sample(distr);
+
+        assert_eq!(from(MF::add(to(a), to(b))), BF::add(a, b));
+        assert_eq!(from(MF::sub(to(a), to(b))), BF::sub(a, b));
+        assert_eq!(from(MF::mul(to(a), to(b))), BF::mul(a, b));
+        if b != 0 {
+            assert_eq!(from(MF::inv(to(b))), BF::inv(b));
+        }
+    }
+}
+
+fn check_conditional_select<F: Field>() {
+    let mut rng = thread_rng();
+    let distr = Uniform::new_inclusive(F::ZERO, F::MINUS_ONE);
+
+    for _ in 0..100 {
+        let a = rng.sample(distr);
+        let b = rng.sample(distr);
+
+        assert_eq!(F::conditional_select(a, b, Choice::from(0)), a);
+        assert_eq!(F::conditional_select(a, b, Choice::from(1)), b);
+
+        let mut c = a;
+        F::conditional_assign(&mut c, b, Choice::from(0));
+        assert_eq!(c, a);
+        F::conditional_assign(&mut c, b, Choice::from(1));
+        assert_eq!(c, b);
+    }
+}
+
+#[test]
+fn test_conditional_select() {
+    check_conditional_select::<U8FieldEval<61>>();
+    check_conditional_select::<U16FieldEval<12289>>();
+    check_conditional_select::<FF>();
+    check_conditional_select::<U64FieldEval<1125899906826241>>();
+    check_conditional_select::<U32MontgomeryField<132120577>>();
+    check_conditional_select::<U64MontgomeryField<4611686018427387847>>();
+}
+
+fn check_ct_eq<F: Field>() {
+    let mut rng = thread_rng();
+    let distr = Uniform::new(F::ZERO, F::MODULUS_VALUE);
+
+    for _ in 0..1000 {
+        let a = rng.sample(distr);
+        let b = if rng.gen() { a } else { rng.sample(distr) };
+        assert_eq!(bool::from(F::ct_eq(a, b)), a == b);
+    }
+    assert!(bool::from(F::ct_eq(F::ONE, F::ONE)));
+    assert!(!bool::from(F::ct_eq(F::ZERO, F::MINUS_ONE)));
+}
+
+#[test]
+fn test_ct_eq() {
+    check_ct_eq::<U8FieldEval<61>>();
+    check_ct_eq::<U16FieldEval<12289>>();
+    check_ct_eq::<FF>();
+    check_ct_eq::<U64FieldEval<1125899906826241>>();
+    check_ct_eq::<U32MontgomeryField<132120577>>();
+    check_ct_eq::<U64MontgomeryField<4611686018427387847>>();
+}
+
+fn check_bytes_round_trip<F: Field>(byte_len: usize) {
+    assert_eq!(F::byte_len(), byte_len);
+
+    let mut rng = thread_rng();
+    let distr = Uniform::new(F::ZERO, F::MODULUS_VALUE);
+    for _ in 0..1000 {
+        let canonical = rng.sample(&distr);
+        let value = F::from_canonical(canonical);
+
+        let le = F::to_bytes_le(value);
+        let be = F::to_bytes_be(value);
+        assert_eq!(le.len(), byte_len);
+        assert_eq!(be.iter().rev().copied().collect::<Vec<u8>>(), le);
+        assert_eq!(F::from_bytes_le(&le).unwrap(), value);
+        assert_eq!(F::from_bytes_be(&be).unwrap(), value);
+    }
+
+    let minus_one = F::to_bytes_le(F::MINUS_ONE);
+    let mut out_of_range = minus_one.clone();
+    // The lowest byte of `MODULUS_VALUE - 1` is not `0xFF` here, so this gives `MODULUS_VALUE`.
+    out_of_range[0] += 1;
+    assert!(F::from_bytes_le(&out_of_range).is_err());
+    out_of_range.reverse();
+    assert!(F::from_bytes_be(&out_of_range).is_err());
+    assert!(F::from_bytes_le(&vec![0xFF; byte_len]).is_err());
+
+    assert!(F::from_bytes_le(&minus_one[1..]).is_err());
+    assert!(F::from_bytes_le(&[minus_one.as_slice(), &[0]].concat()).is_err());
+}
+
+#[test]
+fn test_field_bytes() {
+    check_bytes_round_trip::<U8FieldEval<61>>(1);
+    check_bytes_round_trip::<U16FieldEval<12289>>(2);
+    check_bytes_round_trip::<FF>(4);
+    check_bytes_round_trip::<U64FieldEval<1125899906826241>>(7);
+    check_bytes_round_trip::<U32MontgomeryField<132120577>>(4);
+    check_bytes_round_trip::<U64MontgomeryField<4611686018427387847>>(8);
+
+    assert_eq!(FF::to_bytes_le(1), [1, 0, 0, 0]);
+    assert_eq!(FF::to_bytes_be(0x01020304), [1, 2, 3, 4]);
+    assert_eq!(
+        U32MontgomeryField::<132120577>::to_bytes_le(U32MontgomeryField::<132120577>::ONE),
+        [1, 0, 0, 0]
+    );
+}
+
+fn check_dyn_field<F: Field>(field: &dyn DynField)
+where
+    F::ValueT: Into<u64>,
+{
+    assert_eq!(field.modulus_value(), F::MODULUS_VALUE.into());
+    assert_eq!(field.byte_len(), F::byte_len());
+    assert!(field.inv(0).is_err());
+
+    let mut rng = thread_rng();
+    let distr = Uniform::new(F::ZERO, F::MODULUS_VALUE);
+    let to = |v: F::ValueT| -> u64 { F::to_canonical(v).into() };
+    for _ in 0..1000 {
+        let a = rng.sample(&distr);
+        let b = rng.sample(&distr);
+        let (a_s, b_s) = (F::from_canonical(a), F::from_canonical(b));
+        let (a, b) = (a.into(), b.into());
+
+        assert_eq!(field.add(a, b), to(F::add(a_s, b_s)));
+        assert_eq!(field.sub(a, b), to(F::sub(a_s, b_s)));
+        assert_eq!(field.neg(a), to(F::neg(a_s)));
+        assert_eq!(field.mul(a, b), to(F::mul(a_s, b_s)));
+        if b != 0 {
+            assert_eq!(field.inv(b).unwrap(), to(F::inv(b_s)));
+        }
+
+        let bytes = field.to_bytes_le(a);
+        assert_eq!(bytes, F::to_bytes_le(a_s));
+        assert_eq!(field.from_bytes_le(&bytes).unwrap(), a);
+    }
+}
+
+#[test]
+fn test_dyn_field() {
+    type MF = U32MontgomeryField<132120577>;
+    type BF = U64FieldEval<1125899906826241>;
+
+    check_dyn_field::<FF>(&ErasedField::<FF>::new());
+    check_dyn_field::<MF>(&ErasedField::<MF>::new());
+    check_dyn_field::<BF>(&ErasedField::<BF>::new());
+
+    let fields: [Box<dyn DynField>; 2] = [
+        Box::new(ErasedField::<FF>::new()),
+        Box::new(ErasedField::<BF>::new()),
+    ];
+    let products: Vec<u64> = fields.iter().map(|f| f.mul(1 << 20, 1 << 20)).collect();
+    assert_eq!(products, [(1u64 << 40) % 132120577, 1 << 40]);
+}
@@ -0,0 +1,65 @@
+use algebra::random::{
+    sample_ternary_values, BinarySampler, DiscreteGaussian, NoiseDistribution, TernarySampler,
+};
+use rand::{prelude::Distribution, thread_rng};
+
+const Q: u32 = 132120577;
+const N: usize = 1 << 16;
+
+/// Lifts `v` modulo [`Q`] to `(-Q/2, Q/2]`.
+fn centered(v: u32) -> f64 {
+    if v > Q / 2 {
+        v as f64 - Q as f64
+    } else {
+        v as f64
+    }
+}
+
+fn check_moments<D: NoiseDistribution<u32>>(distr: &D, tolerance: f64) {
+    let mut rng = thread_rng();
+    let values = distr.sample_values(N, &mut rng);
+
+    let mean = values.iter().copied().map(centered).sum::<f64>() / N as f64;
+    let variance = values
+        .iter()
+        .map(|&v| (centered(v) - mean).powi(2))
+        .sum::<f64>()
+        / N as f64;
+
+    assert!((mean - distr.mean()).abs() < tolerance);
+    assert!((variance - distr.variance()).abs() < tolerance * distr.variance().max(1.0));
+}
+
+#[test]
+fn test_noise_distribution_moments() {
+    check_moments(&BinarySampler, 0.02);
+    check_moments(&TernarySampler::new(Q - 1), 0.02);
+    // Rounding adds about `1/12` to the variance of the gaussian.
+    check_moments(&DiscreteGaussian::new(0.0, 3.2, Q - 1).unwrap(), 0.1);
+}
+
+#[test]
+fn test_ternary_sampler() {
+    let mut rng = thread_rng();
+    let sampler = TernarySampler::new(Q - 1);
+
+    let mut counts = [0usize; 3];
+    let values: Vec<u32> = sampler
+        .sample_iter(&mut rng)
+        .take(N)
+        .chain(sample_ternary_values(Q - 1, N, &mut rng))
+        .collect();
+    for v in values {
+        match v {
+            0 => counts[0] += 1,
+            1 => counts[1] += 1,
+            v if v == Q - 1 => counts[2] += 1,
+            _ => panic!("{v} is not ternary"),
+        }
+    }
+
+    let total = (2 * N) as f64;
+    assert!((counts[0] as f64 / total - 0.5).abs() < 0.02);
+    assert!((counts[1] as f64 / total - 0.25).abs() < 0.02);
+    assert!((counts[2] as f64 / total - 0.25).abs() < 0.02);
+}
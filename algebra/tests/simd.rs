@@ -0,0 +1,125 @@
+#![cfg(feature = "simd")]
+
+use algebra::{
+    modulus::BarrettModulus,
+    ntt::{FieldTableWithShoupRoot, NttTable, NumberTheoryTransform},
+    reduce::{ReduceDotProduct, ReduceMul},
+    Field, U32FieldEval,
+};
+use rand::{distributions::Uniform, thread_rng, Rng};
+
+#[test]
+fn test_simd_reduce_mul_slice() {
+    let mut rng = thread_rng();
+
+    // odd moduli take the SIMD path when available, the even one is always scalar
+    for q in [3u32, 12289, 132120577, (1 << 30) - 35, 1000] {
+        let modulus = <BarrettModulus<u32>>::new(q);
+        let distr = Uniform::new(0, q);
+
+        for len in [0, 1, 7, 8, 9, 1023, 4096] {
+            let a: Vec<u32> = (&mut rng).sample_iter(distr).take(len).collect();
+            let b: Vec<u32> = (&mut rng).sample_iter(distr).take(len).collect();
+
+            let expected: Vec<u32> = a
+                .iter()
+                .zip(&b)
+                .map(|(&x, &y)| modulus.reduce_mul(x, y))
+                .collect();
+
+            let mut scalar = a.clone();
+            modulus.reduce_mul_assign_slice_scalar(&mut scalar, &b);
+            assert_eq!(scalar, expected);
+
+            let mut simd = a;
+            modulus.reduce_mul_assign_slice(&mut simd, &b);
+            assert_eq!(simd, expected, "modulus {q}, length {len}");
+        }
+
+        // the largest values
+        let mut a = vec![q - 1; 16];
+        modulus.reduce_mul_assign_slice(&mut a, &[q - 1; 16]);
+        assert_eq!(a, vec![1 % q; 16]);
+    }
+}
+
+#[test]
+fn test_simd_reduce_dot_product() {
+    let mut rng = thread_rng();
+
+    for q in [3u32, 12289, 132120577, (1 << 30) - 35, 1000] {
+        let modulus = <BarrettModulus<u32>>::new(q);
+        let distr = Uniform::new(0, q);
+
+        for len in [0, 1, 3, 4, 7, 8, 9, 17, 1023, 4096] {
+            let a: Vec<u32> = (&mut rng).sample_iter(distr).take(len).collect();
+            let b: Vec<u32> = (&mut rng).sample_iter(distr).take(len).collect();
+
+            let expected = a
+                .iter()
+                .zip(&b)
+                .map(|(&x, &y)| x as u128 * y as u128)
+                .sum::<u128>()
+                % q as u128;
+
+            assert_eq!(modulus.reduce_dot_product_scalar(&a, &b) as u128, expected);
+            assert_eq!(
+                modulus.reduce_dot_product(&a, &b) as u128,
+                expected,
+                "modulus {q}, length {len}"
+            );
+        }
+
+        // the largest values
+        let a = vec![q - 1; 4099];
+        let expected = (4099 * (q as u128 - 1) * (q as u128 - 1) % q as u128) as u32;
+        assert_eq!(modulus.reduce_dot_product(&a, &a), expected);
+    }
+}
+
+#[test]
+fn test_simd_transform() {
+    type Fp = U32FieldEval<132120577>;
+
+    let mut rng = thread_rng();
+    let modulus = Fp::MODULUS;
+    let q = Fp::MODULUS_VALUE;
+    let distr = Uniform::new(0, q);
+
+    // the small stages have fewer pairs than the lanes of a vector and stay scalar
+    for log_n in 1..=10 {
+        let n = 1usize << log_n;
+        let table = <FieldTableWithShoupRoot<Fp>>::new(modulus, log_n).unwrap();
+
+        let a: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+        let b: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+
+        let mut expected = vec![0u32; n];
+        for i in 0..n {
+            for j in 0..n {
+                let product = modulus.reduce_mul(a[i], b[j]);
+                let k = i + j;
+                if k < n {
+                    expected[k] = (expected[k] + product) % q;
+                } else {
+                    expected[k - n] = (expected[k - n] + q - product) % q;
+                }
+            }
+        }
+
+        let mut x = a.clone();
+        let mut y = b;
+        table.transform_slice(&mut x);
+        table.transform_slice(&mut y);
+        x.iter_mut()
+            .zip(y)
+            .for_each(|(u, v)| *u = modulus.reduce_mul(*u, v));
+        table.inverse_transform_slice(&mut x);
+        assert_eq!(x, expected, "log n {log_n}");
+
+        let mut x = a.clone();
+        table.transform_slice(&mut x);
+        table.inverse_transform_slice(&mut x);
+        assert_eq!(x, a);
+    }
+}
@@ -1,7 +1,8 @@
 use algebra::{
     modulus::BarrettModulus,
-    ntt::{NttTable, NumberTheoryTransform, TableWithShoupRoot},
+    ntt::{FieldTableWithShoupRoot, NttTable, NumberTheoryTransform, TableWithShoupRoot},
     reduce::{ReduceAdd, ReduceAddAssign, ReduceMul, ReduceSubAssign},
+    Field, U32FieldEval,
 };
 use rand::{distributions::Uniform, prelude::Distribution, thread_rng, Rng};
 
@@ -107,3 +108,76 @@ fn test_transform_monomial() {
         assert_eq!(a, b);
     }
 }
+
+#[test]
+fn test_blocked_transform() {
+    type Fp = U32FieldEval<132120577>;
+
+    let mut rng = thread_rng();
+    let q = Fp::MODULUS_VALUE;
+
+    for log_n in 10..=16 {
+        let n = 1usize << log_n;
+        let table = <FieldTableWithShoupRoot<Fp>>::new(Fp::MODULUS, log_n).unwrap();
+
+        let a: Vec<u32> = Uniform::new(0, q).sample_iter(&mut rng).take(n).collect();
+
+        let mut serial = a.clone();
+        let mut blocked = a.clone();
+        table.transform_slice(&mut serial);
+        table.blocked_transform_slice(&mut blocked);
+        assert_eq!(serial, blocked);
+
+        table.inverse_transform_slice(&mut serial);
+        table.blocked_inverse_transform_slice(&mut blocked);
+        assert_eq!(serial, blocked);
+        assert_eq!(blocked, a);
+
+        // lazy inputs lie in `[0, 4q)`
+        let lazy: Vec<u32> = Uniform::new(0, q << 2)
+            .sample_iter(&mut rng)
+            .take(n)
+            .collect();
+
+        let mut serial = lazy.clone();
+        let mut blocked = lazy;
+        table.lazy_transform_slice(&mut serial);
+        table.lazy_blocked_transform_slice(&mut blocked);
+        assert_eq!(serial, blocked);
+
+        table.lazy_inverse_transform_slice(&mut serial);
+        table.lazy_blocked_inverse_transform_slice(&mut blocked);
+        assert_eq!(serial, blocked);
+    }
+}
+
+#[test]
+fn test_mul_chain() {
+    let mut rng = thread_rng();
+    let modulus = <BarrettModulus<P>>::new(M);
+    let table = <TableWithShoupRoot<P>>::new(modulus, N.trailing_zeros()).unwrap();
+
+    let dis = Uniform::new(0, M);
+
+    let polys: Vec<Vec<P>> = (0..4)
+        .map(|_| dis.sample_iter(&mut rng).take(N).collect())
+        .collect();
+
+    let expected = polys[1..].iter().fold(polys[0].clone(), |acc, poly| {
+        naive_mul(&acc, poly, &modulus)
+    });
+
+    // The products stay in bit-reversed order until the single inverse transform.
+    let mut acc = polys[0].clone();
+    table.transform_slice(&mut acc);
+    for poly in &polys[1..] {
+        let mut ntt_poly = poly.clone();
+        table.transform_slice(&mut ntt_poly);
+        acc.iter_mut()
+            .zip(ntt_poly)
+            .for_each(|(x, y)| *x = modulus.reduce_mul(*x, y));
+    }
+    table.inverse_transform_slice(&mut acc);
+
+    assert_eq!(acc, expected);
+}
@@ -180,7 +180,36 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
     }
 
     /// Complete the bootstrapping operation with LWE Ciphertext *`c`* and lookup table `lut`.
-    pub fn bootstrap(&self, mut c: LweCiphertext<C>, lut: FieldPolynomial<Q>) -> LweCiphertext<C> {
+    ///
+    /// `q/8` is added to the result of blind rotation,
+    /// so a look-up table with values `±q/8` gives the boolean messages `0` and `1`.
+    #[inline]
+    pub fn bootstrap(&self, c: LweCiphertext<C>, lut: FieldPolynomial<Q>) -> LweCiphertext<C> {
+        self.bootstrap_with_offset(c, lut, Some(Q::MODULUS_VALUE >> 3u32))
+    }
+
+    /// Complete the programmable bootstrapping operation with LWE Ciphertext *`c`* and lookup table `lut`.
+    ///
+    /// If `c` encrypts `m` and `lut` is the negacyclic look-up table of `f`
+    /// with values encoded under the ring modulus, see [`LookUpTable::negacyclic_lut`],
+    /// the result encrypts `f(m)` with refreshed noise.
+    /// As the look-up table is negacyclic, `f` must satisfy `f(m + t/2) = -f(m)`,
+    /// or `m` must be less than `t/2`.
+    #[inline]
+    pub fn programmable_bootstrap(
+        &self,
+        c: LweCiphertext<C>,
+        lut: FieldPolynomial<Q>,
+    ) -> LweCiphertext<C> {
+        self.bootstrap_with_offset(c, lut, None)
+    }
+
+    fn bootstrap_with_offset(
+        &self,
+        mut c: LweCiphertext<C>,
+        lut: FieldPolynomial<Q>,
+        offset: Option<<Q as Field>::ValueT>,
+    ) -> LweCiphertext<C> {
         let parameters = self.parameters();
         let twice_ring_dimension_value =
             C::try_from(parameters.ring_dimension() << 1).ok().unwrap();
@@ -195,7 +224,9 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
         // blind rotation
         let mut acc = self.blind_rotation_key.blind_rotate(lut, &c);
 
-        <Q as Field>::MODULUS.reduce_add_assign(&mut acc.b_mut()[0], Q::MODULUS_VALUE >> 3u32);
+        if let Some(offset) = offset {
+            <Q as Field>::MODULUS.reduce_add_assign(&mut acc.b_mut()[0], offset);
+        }
 
         // key switch and modulus switch (N, Q) -> (n, q)
         match parameters.steps() {
@@ -286,6 +317,20 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         self.ek.bootstrap(c, lut)
     }
 
+    /// Complete the programmable bootstrapping operation with LWE Ciphertext *`c`* and lookup table `lut`.
+    ///
+    /// If `c` encrypts `m` and `lut` is the negacyclic look-up table of `f`,
+    /// the result encrypts `f(m)`. `f` must satisfy `f(m + t/2) = -f(m)`,
+    /// or `m` must be less than `t/2`.
+    #[inline]
+    pub fn programmable_bootstrap(
+        &self,
+        c: LweCiphertext<C>,
+        lut: FieldPolynomial<Q>,
+    ) -> LweCiphertext<C> {
+        self.ek.programmable_bootstrap(c, lut)
+    }
+
     /// Performs the homomorphic not operation.
     ///
     /// # Arguments
@@ -8,11 +8,16 @@ use super::{BooleanFheParameters, ConstParameters, Steps};
 type Fp = U32FieldEval<132120577>;
 
 /// Default 128-bits security Parameters
+///
+/// The LWE part is [`fhe_core::LweParameters::preset_128bit_tfhe`] and the blind rotation
+/// part [`fhe_core::GadgetRlweParameters::preset_128bit_tfhe`], so both are at least
+/// 128 bits by their `estimated_security_bits`. The LWE dimension is 560, the 512 used
+/// before is only estimated at about 123 bits.
 pub static DEFAULT_128_BITS_PARAMETERS: LazyLock<
     BooleanFheParameters<u16, PowOf2Modulus<u16>, Fp>,
 > = LazyLock::new(|| {
     BooleanFheParameters::<u16, PowOf2Modulus<u16>, Fp>::new(ConstParameters {
-        lwe_dimension: 512,
+        lwe_dimension: 560,
         lwe_plain_modulus: 4,
         lwe_cipher_modulus: ModulusValue::PowerOf2(1 << 14),
         lwe_noise_standard_deviation: 3.20,
@@ -1,5 +1,7 @@
 //! The parameters of the fully homomorphic encryption scheme.
 
+use std::sync::Arc;
+
 use algebra::decompose::NonPowOf2ApproxSignedBasis;
 use algebra::integer::Bits;
 use algebra::random::DiscreteGaussian;
@@ -7,7 +9,11 @@ use algebra::reduce::{ModulusValue, RingReduce};
 use algebra::Field;
 use algebra::{integer::UnsignedInteger, NttField};
 use fhe_core::{FHECoreError, GadgetRlweParameters as BlindRotationParameters};
-use fhe_core::{KeySwitchingParameters, LweParameters, LweSecretKeyType, RingSecretKeyType};
+use fhe_core::{
+    KeySwitchingParameters, LweParameters, LweSecretKeyType, NoiseDistributionType,
+    RingSecretKeyType,
+};
+use num_traits::ConstOne;
 
 mod constants;
 mod steps;
@@ -118,7 +124,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         }
         let lwe_cipher_modulus = LweModulus::from_value(lwe_cipher_modulus);
 
-        let lwe_params = LweParameters::new(
+        let lwe_params = LweParameters::new_unchecked(
             lwe_dimension,
             t,
             lwe_cipher_modulus,
@@ -136,6 +142,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
                 None,
             ),
             secret_key_type: ring_secret_key_type,
+            noise_distribution_type: NoiseDistributionType::Gaussian,
         };
 
         let log_modulus = match steps {
@@ -263,7 +270,12 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
     /// Gets the ring noise distribution.
     #[inline]
     pub fn ring_noise_distribution(&self) -> DiscreteGaussian<<Q as Field>::ValueT> {
-        DiscreteGaussian::new(0.0, self.ring_noise_standard_deviation(), Q::MINUS_ONE).unwrap()
+        DiscreteGaussian::new(
+            0.0,
+            self.ring_noise_standard_deviation(),
+            Q::MODULUS_VALUE - <<Q as Field>::ValueT as ConstOne>::ONE,
+        )
+        .unwrap()
     }
 
     /// Gets the key_switching noise distribution.
@@ -274,7 +286,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         DiscreteGaussian::new(
             0.0,
             self.key_switching_noise_standard_deviation(),
-            Q::MINUS_ONE,
+            Q::MODULUS_VALUE - <<Q as Field>::ValueT as ConstOne>::ONE,
         )
         .unwrap()
     }
@@ -302,10 +314,10 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         &self.lwe_params
     }
 
-    /// Generates the NTT table.
+    /// Returns the shared NTT table, see [`BlindRotationParameters::generate_ntt_table`].
     #[inline]
-    pub fn generate_ntt_table_for_rlwe(&self) -> <Q as NttField>::Table {
-        Q::generate_ntt_table(self.ring_dimension().trailing_zeros()).unwrap()
+    pub fn generate_ntt_table_for_rlwe(&self) -> Arc<<Q as NttField>::Table> {
+        self.blind_rotation_params.generate_ntt_table().unwrap()
     }
 
     /// Returns the key switching params of this [`BooleanFheParameters<C, Q>`].
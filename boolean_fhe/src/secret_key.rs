@@ -67,7 +67,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> SecretKeyPack<C
             rlwe_secret_key,
             ntt_rlwe_secret_key,
             parameters,
-            ntt_table: Arc::new(ntt_table),
+            ntt_table,
         }
     }
 
@@ -0,0 +1,112 @@
+use algebra::{Field, U32FieldEval};
+use boolean_fhe::{
+    Decryptor, Encryptor, Evaluator, KeyGen, LookUpTable, DEFAULT_128_BITS_PARAMETERS,
+};
+use fhe_core::{GadgetRlweParameters, LweParameters};
+use rand::thread_rng;
+
+type Fp = U32FieldEval<132120577>;
+
+#[test]
+fn test_programmable_bootstrap() {
+    let mut rng = thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+    let t = params.lwe_plain_modulus() as u32;
+    let log_t = t.trailing_zeros();
+    let n = params.ring_dimension();
+
+    let sk = KeyGen::generate_secret_key(params, &mut rng);
+    let encryptor = Encryptor::new(&sk);
+    let decryptor = Decryptor::new(&sk);
+    let evaluator = Evaluator::new(&sk, &mut rng);
+
+    let q = Fp::MODULUS_VALUE;
+    let encode = |m: u32| -> u32 { (m as f64 * q as f64 / t as f64).round() as u32 % q };
+    let neg = |v: u32| -> u32 { Fp::neg(v) };
+
+    // identity on the messages with padding bit, `m < t/2`,
+    // extended negacyclically with `f(m + t/2) = -f(m)`
+    let identity: Vec<u32> = (0..t / 2)
+        .map(encode)
+        .chain((0..t / 2).map(|m| neg(encode(m))))
+        .collect();
+    let identity_lut = <&[u32] as LookUpTable<Fp>>::negacyclic_lut(&identity.as_slice(), n, log_t);
+
+    for m in 0..t / 2 {
+        for _ in 0..4 {
+            let c = encryptor.encrypt(m as u8, &mut rng);
+            let c = evaluator.programmable_bootstrap(c, identity_lut.clone());
+            let decrypted: u8 = decryptor.decrypt(&c);
+            assert_eq!(decrypted as u32, m);
+        }
+    }
+
+    // sign over all messages, `1` for `m < t/2` and `-1` otherwise
+    let one = encode(1);
+    let sign: Vec<u32> = (0..t)
+        .map(|m| if m < t / 2 { one } else { neg(one) })
+        .collect();
+    let sign_lut = <&[u32] as LookUpTable<Fp>>::negacyclic_lut(&sign.as_slice(), n, log_t);
+
+    for m in 0..t {
+        let expected = if m < t / 2 { 1 } else { t - 1 };
+        for _ in 0..4 {
+            let c = encryptor.encrypt(m as u8, &mut rng);
+            let c = evaluator.programmable_bootstrap(c, sign_lut.clone());
+            let decrypted: u8 = decryptor.decrypt(&c);
+            assert_eq!(decrypted as u32, expected);
+        }
+    }
+}
+
+/// Every gate runs a blind rotation and a key switching, so this checks them end to end
+/// over the barrett field of the default parameters.
+#[test]
+fn test_gates() {
+    let mut rng = thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let sk = KeyGen::generate_secret_key(params, &mut rng);
+    let encryptor = Encryptor::new(&sk);
+    let decryptor = Decryptor::new(&sk);
+    let evaluator = Evaluator::new(&sk, &mut rng);
+
+    let decrypt = |c| -> u8 { decryptor.decrypt(&c) };
+
+    for a in 0..=1u8 {
+        let x = encryptor.encrypt(a, &mut rng);
+        assert_eq!(decrypt(evaluator.not(&x)), a ^ 1);
+
+        for b in 0..=1u8 {
+            let y = encryptor.encrypt(b, &mut rng);
+            assert_eq!(decrypt(evaluator.nand(&x, &y)), (a & b) ^ 1);
+            assert_eq!(decrypt(evaluator.and(&x, &y)), a & b);
+            assert_eq!(decrypt(evaluator.or(&x, &y)), a | b);
+            assert_eq!(decrypt(evaluator.nor(&x, &y)), (a | b) ^ 1);
+            assert_eq!(decrypt(evaluator.xor(&x, &y)), a ^ b);
+            assert_eq!(decrypt(evaluator.xnor(&x, &y)), (a ^ b) ^ 1);
+
+            for c in 0..=1u8 {
+                let z = encryptor.encrypt(c, &mut rng);
+                let majority = (a & b) | (b & c) | (a & c);
+                let mux = if a == 1 { b } else { c };
+                assert_eq!(decrypt(evaluator.majority(&x, &y, &z)), majority);
+                assert_eq!(decrypt(evaluator.mux(&x, &y, &z)), mux);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_default_parameters_security() {
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let lwe = LweParameters::preset_128bit_tfhe();
+    assert_eq!(params.lwe_dimension(), lwe.dimension());
+    assert!(params.lwe_params().estimated_security_bits() >= 128.0);
+
+    let rlwe = GadgetRlweParameters::<Fp>::preset_128bit_tfhe();
+    assert_eq!(params.ring_dimension(), rlwe.dimension());
+}
@@ -0,0 +1,42 @@
+use algebra::modulus::PowOf2Modulus;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fhe_core::{LweCiphertext, LweParameters, LweSecretKey, LweSecretKeyType};
+use rand::{distributions::Uniform, Rng};
+
+type M = u8;
+type C = u16;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    const BATCH: usize = 1024;
+
+    let mut rng = rand::thread_rng();
+
+    let params = LweParameters::new_unchecked(
+        512,
+        4 as C,
+        PowOf2Modulus::<C>::new(2048),
+        LweSecretKeyType::Binary,
+        3.20,
+    );
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    let distr = Uniform::new(0, 4 as M);
+    let messages: Vec<M> = (&mut rng).sample_iter(distr).take(BATCH).collect();
+
+    c.bench_function("lwe encrypt serial loop", |b| {
+        b.iter(|| {
+            let ciphertexts: Vec<LweCiphertext<C>> = messages
+                .iter()
+                .map(|&m| params.encrypt(&sk, m, &mut rng))
+                .collect();
+            black_box(ciphertexts)
+        })
+    });
+
+    c.bench_function("lwe encrypt batch", |b| {
+        b.iter(|| black_box(params.encrypt_batch(&sk, black_box(&messages), &mut rng)))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -5,7 +5,6 @@ use algebra::{
     modulus::PowOf2Modulus,
     ntt::NumberTheoryTransform,
     polynomial::FieldPolynomial,
-    random::DiscreteGaussian,
     reduce::{ReduceAddAssign, ReduceMul, ReduceSubAssign},
     Field, NttField,
 };
@@ -14,7 +13,7 @@ use lattice::{
     NttGadgetRlwe,
 };
 use num_traits::One;
-use rand::{CryptoRng, Rng};
+use rand::{distributions::Distribution, CryptoRng, Rng};
 
 use crate::{NttRlweSecretKey, RlweCiphertext, RlweSecretKey};
 
@@ -45,17 +44,18 @@ impl<F: NttField> AutoSpace<F> {
 impl<F: NttField> AutoKey<F> {
     /// Creates a new [`AutoKey<F>`].
     #[inline]
-    pub fn new<R>(
+    pub fn new<R, D>(
         secret_key: &RlweSecretKey<F>,
         ntt_secret_key: &NttRlweSecretKey<F>,
         degree: usize,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: Arc<<F as NttField>::Table>,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let rlwe_dimension = secret_key.coeff_count();
         assert!(degree > 0 && degree < rlwe_dimension << 1);
@@ -63,7 +63,7 @@ impl<F: NttField> AutoKey<F> {
             NttGadgetRlwe::generate_random_neg_secret_sample(
                 ntt_secret_key,
                 basis,
-                gaussian,
+                noise,
                 &ntt_table,
                 rng,
             )
@@ -74,7 +74,7 @@ impl<F: NttField> AutoKey<F> {
                 ntt_secret_key,
                 &auto_sk,
                 basis,
-                gaussian,
+                noise,
                 &ntt_table,
                 rng,
             )
@@ -87,6 +87,12 @@ impl<F: NttField> AutoKey<F> {
         }
     }
 
+    /// Returns the degree `k` of the automorphism `X -> X^k` of this [`AutoKey<F>`].
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
     /// Performs automorphism on the given RLWE ciphertext.
     #[inline]
     pub fn automorphism(&self, ciphertext: &RlweCiphertext<F>) -> RlweCiphertext<F> {
@@ -173,7 +179,7 @@ fn poly_auto_inplace<F: NttField>(
 
 #[cfg(test)]
 mod tests {
-    use algebra::{reduce::ReduceNeg, Field, U32FieldEval};
+    use algebra::{random::DiscreteGaussian, reduce::ReduceNeg, Field, U32FieldEval};
     use lattice::Rlwe;
     use rand::{distributions::Uniform, prelude::Distribution};
 
@@ -233,14 +239,14 @@ mod tests {
             crate::RingSecretKeyType::Ternary,
         );
         let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
-        let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+        let noise = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
         let basis = NonPowOf2ApproxSignedBasis::new(Fp::MODULUS_VALUE, 4, None);
 
         let values: Vec<ValT> = distr.sample_iter(&mut rng).take(N).collect();
         let encoded_values = PolyT::new(values.iter().copied().map(encode).collect());
 
         let mut cipher =
-            <Rlwe<Fp>>::generate_random_zero_sample(&ntt_sk, gaussian, &ntt_table, &mut rng);
+            <Rlwe<Fp>>::generate_random_zero_sample(&ntt_sk, noise, &ntt_table, &mut rng);
         *cipher.b_mut() += &encoded_values;
 
         let auto_key = AutoKey::new(
@@ -248,7 +254,7 @@ mod tests {
             &ntt_sk,
             N + 1,
             &basis,
-            gaussian,
+            noise,
             Arc::clone(&ntt_table),
             &mut rng,
         );
@@ -5,7 +5,6 @@ use algebra::{
     integer::{AsInto, UnsignedInteger},
     ntt::NttTable,
     polynomial::FieldPolynomial,
-    random::DiscreteGaussian,
     reduce::ReduceNegAssign,
     Field, NttField,
 };
@@ -13,7 +12,7 @@ use lattice::{
     utils::{NttRlweSpace, PolyDecomposeSpace, RlweSpace},
     NttRgsw, Rlwe,
 };
-use rand::{CryptoRng, Rng};
+use rand::{distributions::Distribution, CryptoRng, Rng};
 
 use crate::{utils::Pool, LweCiphertext, LweSecretKey, NttRlweSecretKey, RlweCiphertext};
 
@@ -136,17 +135,18 @@ impl<F: NttField> BinaryBlindRotationKey<F> {
     }
 
     /// Generates the [`BinaryBlindRotationKey<F>`].
-    pub(crate) fn generate<R, C>(
+    pub(crate) fn generate<R, C, D>(
         lwe_secret_key: &LweSecretKey<C>,
         rlwe_secret_key: &NttRlweSecretKey<F>,
         blind_rotation_basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: Arc<<F as NttField>::Table>,
         rng: &mut R,
     ) -> Self
     where
         C: UnsignedInteger,
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let key = lwe_secret_key
             .as_ref()
@@ -156,7 +156,7 @@ impl<F: NttField> BinaryBlindRotationKey<F> {
                     <NttRgsw<F>>::generate_random_zero_sample(
                         rlwe_secret_key,
                         blind_rotation_basis,
-                        gaussian,
+                        noise,
                         &ntt_table,
                         rng,
                     )
@@ -164,7 +164,7 @@ impl<F: NttField> BinaryBlindRotationKey<F> {
                     <NttRgsw<F>>::generate_random_one_sample(
                         rlwe_secret_key,
                         blind_rotation_basis,
-                        gaussian,
+                        noise,
                         &ntt_table,
                         rng,
                     )
@@ -5,10 +5,10 @@ use std::sync::Arc;
 
 use algebra::{
     decompose::NonPowOf2ApproxSignedBasis, integer::UnsignedInteger, polynomial::FieldPolynomial,
-    random::DiscreteGaussian, Field, NttField,
+    Field, NttField,
 };
 pub use binary::BinaryBlindRotationKey;
-use rand::{CryptoRng, Rng};
+use rand::{distributions::Distribution, CryptoRng, Rng};
 pub use ternary::TernaryBlindRotationKey;
 
 use crate::{LweCiphertext, LweSecretKey, LweSecretKeyType, NttRlweSecretKey, RlweCiphertext};
@@ -57,24 +57,25 @@ impl<F: NttField> BlindRotationKey<F> {
     }
 
     /// Generates the [`BlindRotationKey<F>`].
-    pub fn generate<C, R>(
+    pub fn generate<C, R, D>(
         lwe_secret_key: &LweSecretKey<C>,
         rlwe_secret_key: &NttRlweSecretKey<F>,
         blind_rotation_basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: Arc<<F as NttField>::Table>,
         rng: &mut R,
     ) -> Self
     where
         C: UnsignedInteger,
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         match lwe_secret_key.distr() {
             LweSecretKeyType::Binary => BlindRotationKey::Binary(BinaryBlindRotationKey::generate(
                 lwe_secret_key,
                 rlwe_secret_key,
                 blind_rotation_basis,
-                gaussian,
+                noise,
                 ntt_table,
                 rng,
             )),
@@ -83,7 +84,7 @@ impl<F: NttField> BlindRotationKey<F> {
                     lwe_secret_key,
                     rlwe_secret_key,
                     blind_rotation_basis,
-                    gaussian,
+                    noise,
                     ntt_table,
                     rng,
                 ))
@@ -9,3 +9,9 @@ pub type RlweCiphertext<F> = lattice::Rlwe<F>;
 
 /// Ntt version Rlwe Ciphertext
 pub type NttRlweCiphertext<F> = lattice::NttRlwe<F>;
+
+/// Rgsw Ciphertext
+pub type RgswCiphertext<F> = lattice::Rgsw<F>;
+
+/// Ntt version Rgsw Ciphertext
+pub type NttRgswCiphertext<F> = lattice::NttRgsw<F>;
@@ -0,0 +1,156 @@
+//! Constant time helpers for decryption.
+//!
+//! These functions never branch on or index memory by their inputs.
+//! Conditions are turned into all-zero or all-one masks instead.
+//! Whether the generated code stays constant time depends on the compiler
+//! and the target. On common targets the comparisons compile to `setcc`/`cmov`
+//! like instructions.
+
+use algebra::{
+    integer::{ct_eq_mask, ct_mask, UnsignedInteger},
+    reduce::ModulusValue,
+    Choice,
+};
+
+pub use algebra::integer::ct_eq;
+
+/// Returns `C::MAX` if `condition` is `true`, otherwise returns `0`.
+#[inline(always)]
+fn mask<C: UnsignedInteger>(condition: bool) -> C {
+    ct_mask(Choice::from(u8::from(condition)))
+}
+
+/// Calculates `a + b (mod q)` for `a, b < q`.
+#[inline(always)]
+fn add_mod<C: UnsignedInteger>(a: C, b: C, q: C) -> C {
+    let (sum, carry) = a.overflowing_add(b);
+    sum.wrapping_sub(q & mask(carry | (sum >= q)))
+}
+
+/// Calculates `a - b (mod q)` for `a, b < q`.
+#[inline(always)]
+fn sub_mod<C: UnsignedInteger>(a: C, b: C, q: C) -> C {
+    let (diff, borrow) = a.overflowing_sub(b);
+    diff.wrapping_add(q & mask(borrow))
+}
+
+/// Calculates `b - <a, s> (mod q)` for a binary or ternary secret key `s`,
+/// whose coefficients must be `0`, `1` or `q - 1`.
+pub(crate) fn lwe_phase<C: UnsignedInteger>(
+    a: &[C],
+    b: C,
+    secret_key: &[C],
+    q: ModulusValue<C>,
+) -> C {
+    debug_assert_eq!(a.len(), secret_key.len());
+
+    let minus_one = q.modulus_minus_one();
+    // other coefficients would be silently treated as `0`.
+    debug_assert!(secret_key.iter().all(|&s| s <= C::ONE || s == minus_one));
+
+    match q {
+        ModulusValue::Native | ModulusValue::PowerOf2(_) => {
+            let phase = a.iter().zip(secret_key).fold(b, |acc, (&a_i, &s_i)| {
+                let add = a_i & ct_eq_mask(s_i, minus_one);
+                let sub = a_i & ct_eq_mask(s_i, C::ONE);
+                acc.wrapping_add(add).wrapping_sub(sub)
+            });
+            phase & minus_one
+        }
+        ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+            a.iter().zip(secret_key).fold(b, |acc, (&a_i, &s_i)| {
+                let add = a_i & ct_eq_mask(s_i, minus_one);
+                let sub = a_i & ct_eq_mask(s_i, C::ONE);
+                sub_mod(add_mod(acc, add, q), sub, q)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use algebra::{
+        modulus::{BarrettModulus, GoldilocksModulus, PowOf2Modulus},
+        random::sample_ternary_values,
+        reduce::RingReduce,
+    };
+    use rand::{distributions::Uniform, prelude::*};
+
+    use crate::decode;
+
+    use super::*;
+
+    const N: usize = 256;
+
+    fn check_phase<C: UnsignedInteger, M: RingReduce<C>>(modulus: M) {
+        let mut rng = thread_rng();
+        let minus_one = modulus.modulus_minus_one();
+        let distr = Uniform::new_inclusive(C::ZERO, minus_one);
+
+        for _ in 0..100 {
+            let s: Vec<C> = sample_ternary_values(minus_one, N, &mut rng);
+            let a: Vec<C> = (&mut rng).sample_iter(distr).take(N).collect();
+            let b: C = rng.sample(distr);
+
+            let expected = modulus.reduce_sub(b, modulus.reduce_dot_product(&a, &s));
+            assert_eq!(lwe_phase(&a, b, &s, modulus.modulus_value()), expected);
+        }
+    }
+
+    #[test]
+    fn test_lwe_phase() {
+        check_phase(PowOf2Modulus::<u16>::new(1 << 14));
+        check_phase(BarrettModulus::<u32>::new(132120577));
+        check_phase(GoldilocksModulus::new());
+    }
+
+    #[test]
+    fn test_decode_normal() {
+        let mut rng = thread_rng();
+        let q: u64 = GoldilocksModulus::VALUE;
+
+        for t in [2u64, 3, 4, 7, 16] {
+            let reference = |c: u64| {
+                let m = ((c as u128 * t as u128 * 2 + q as u128) / (q as u128 * 2)) as u64;
+                m % t
+            };
+            for _ in 0..1000 {
+                let c: u64 = rng.gen_range(0..q);
+                let m: u64 = decode(c, t, ModulusValue::Prime(q));
+                assert_eq!(m, reference(c));
+            }
+            let m: u64 = decode(q - 1, t, ModulusValue::Prime(q));
+            assert_eq!(m, 0);
+        }
+    }
+
+    fn check_ct_eq<C: UnsignedInteger>() {
+        let mut rng = thread_rng();
+        let distr = Uniform::new_inclusive(C::ZERO, C::MAX);
+        for _ in 0..1000 {
+            let a: C = rng.sample(distr);
+            let b: C = if rng.gen() { a } else { rng.sample(distr) };
+            assert_eq!(bool::from(ct_eq(a, b)), a == b);
+            assert_eq!(ct_eq_mask(a, b), if a == b { C::MAX } else { C::ZERO });
+        }
+        for v in [C::ZERO, C::ONE, C::MAX] {
+            assert!(bool::from(ct_eq(v, v)));
+        }
+        assert!(!bool::from(ct_eq(C::ZERO, C::MAX)));
+        assert!(!bool::from(ct_eq(C::ONE, C::ZERO)));
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        check_ct_eq::<u8>();
+        check_ct_eq::<u16>();
+        check_ct_eq::<u32>();
+        check_ct_eq::<u64>();
+    }
+
+    #[test]
+    fn test_add_sub_mod() {
+        assert_eq!(add_mod(6u8, 250, 251), 5);
+        assert_eq!(sub_mod(6u8, 250, 251), 7);
+    }
+}
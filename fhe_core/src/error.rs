@@ -35,4 +35,93 @@ pub enum FHECoreError {
     /// is not compatible with other parameters.
     #[error("Steps after blind rotation is not compatible with other parameters!")]
     StepsParametersNotCompatible,
+    /// Error that occurs when the loaded parameters are of another format version.
+    #[error("Parameters format version {found} doesn't match the supported version {expected}!")]
+    ParametersVersionMismatch {
+        /// The supported format version.
+        expected: u32,
+        /// The format version of the loaded parameters.
+        found: u32,
+    },
+    /// Error that occurs when the loaded parameters are not valid.
+    #[error("Parameters are corrupted: {0}!")]
+    CorruptedParameters(
+        /// What is wrong with the parameters.
+        &'static str,
+    ),
+    /// Error that occurs when a message does not fit in the message space.
+    #[error("Message {message:?} is not less than the message space {plain_modulus:?}!")]
+    MessageOutOfRange {
+        /// The message.
+        message: Box<dyn Debug>,
+        /// The message space.
+        plain_modulus: Box<dyn Debug>,
+    },
+    /// Error that occurs when the parameters can't be serialized or deserialized.
+    #[cfg(feature = "serde")]
+    #[error("Parameters serialization failed: {0}")]
+    Serialization(#[from] bincode::Error),
+}
+
+/// Errors that may occur when validating parameters.
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum ParameterError {
+    /// Error that occurs when the dimension is zero or not supported.
+    #[error("Dimension {0} is not valid!")]
+    DimensionNotValid(
+        /// The dimension.
+        usize,
+    ),
+    /// Error that occurs when the plain modulus is not in `[2, q)`.
+    #[error("Plain modulus {plain_modulus} is not valid for cipher modulus {cipher_modulus}!")]
+    PlainModulusNotValid {
+        /// The plain modulus.
+        plain_modulus: String,
+        /// The cipher modulus.
+        cipher_modulus: String,
+    },
+    /// Error that occurs when the modulus is not valid.
+    #[error("Modulus {0} is not valid!")]
+    ModulusNotValid(
+        /// The modulus.
+        String,
+    ),
+    /// Error that occurs when the two sides of a switch use different cipher moduli.
+    #[error("Cipher modulus {from} does not match cipher modulus {to}!")]
+    ModulusMismatch {
+        /// The cipher modulus switched from.
+        from: String,
+        /// The cipher modulus switched to.
+        to: String,
+    },
+    /// Error that occurs when a decomposition basis and length do not fit the modulus.
+    #[error("Decomposition with log basis {log_basis} and length {levels} is not valid for log modulus {log_modulus}!")]
+    DecompositionNotValid {
+        /// The log2 of the basis.
+        log_basis: u32,
+        /// The decomposition length.
+        levels: usize,
+        /// The log2 of the modulus.
+        log_modulus: u32,
+    },
+    /// Error that occurs when the noise standard deviation is too small to be secure or not finite.
+    #[error("Noise standard deviation {0} is not valid!")]
+    NoiseNotValid(
+        /// The noise standard deviation.
+        f64,
+    ),
+    /// Error that occurs when the estimated security level is below the required one.
+    #[error("Estimated security of {estimated} bits is below the required {required} bits!")]
+    SecurityTooLow {
+        /// The estimated security bits.
+        estimated: f64,
+        /// The required security bits.
+        required: f64,
+    },
+    /// Error that occurs when a fresh ciphertext fails to decrypt too likely.
+    #[error("Decryption failure probability 2^{0} is too large!")]
+    DecryptionFailureTooLikely(
+        /// The log2 of the estimated failure probability.
+        f64,
+    ),
 }
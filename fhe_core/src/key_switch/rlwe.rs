@@ -1,10 +1,8 @@
 use std::sync::Arc;
 
-use algebra::{
-    decompose::NonPowOf2ApproxSignedBasis, ntt::NttTable, random::DiscreteGaussian, Field, NttField,
-};
+use algebra::{decompose::NonPowOf2ApproxSignedBasis, ntt::NttTable, Field, NttField};
 use lattice::{utils::PolyDecomposeSpace, NttGadgetRlwe, NttRlwe};
-use rand::{CryptoRng, Rng};
+use rand::{distributions::Distribution, CryptoRng, Rng};
 
 use crate::{utils::Pool, NttRlweSecretKey, RlweCiphertext};
 
@@ -35,30 +33,30 @@ impl<Q: NttField> RlweKeySwitchingKey<Q> {
     /// * `s_in` - A reference to the input RLWE secret key.
     /// * `s_out` - A reference to the output RLWE secret key.
     /// * `basis` - The basis for the key switching.
-    /// * `gaussian` - The Gaussian distribution used for generating random samples.
+    /// * `noise` - The noise distribution used for generating random samples.
     /// * `ntt_table` - The NTT table used for Number Theoretic Transform operations.
     /// * `rng` - A mutable reference to a random number generator.
     ///
     /// # Returns
     ///
     /// A new instance of `RlweKeySwitchingKey`.
-    pub fn generate<R>(
+    pub fn generate<R, D>(
         s_in: &NttRlweSecretKey<Q>,
         s_out: &NttRlweSecretKey<Q>,
         basis: &NonPowOf2ApproxSignedBasis<<Q as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<Q as Field>::ValueT>,
+        noise: D,
         ntt_table: Arc<<Q as NttField>::Table>,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<Q as Field>::ValueT> + Copy,
     {
         // other case will be added later.
         assert_eq!(s_in.coeff_count(), s_out.coeff_count());
 
-        let key = NttGadgetRlwe::generate_random_poly_sample(
-            s_out, s_in, basis, gaussian, &ntt_table, rng,
-        );
+        let key =
+            NttGadgetRlwe::generate_random_poly_sample(s_out, s_in, basis, noise, &ntt_table, rng);
 
         Self {
             key,
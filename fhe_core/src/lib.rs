@@ -11,6 +11,7 @@ mod public_key;
 mod secret_key;
 
 mod ciphertext;
+mod constant_time;
 mod plaintext;
 
 mod blind_rotation;
@@ -21,19 +22,31 @@ mod trace;
 
 mod modulus_switch;
 
+#[cfg(any(test, feature = "debug-noise"))]
+mod noise;
 pub mod utils;
 
-pub use error::FHECoreError;
+pub use error::{FHECoreError, ParameterError};
 
-pub use parameter::{GadgetRlweParameters, KeySwitchingParameters, LweParameters};
+#[cfg(feature = "serde")]
+pub use parameter::PARAMETERS_FORMAT_VERSION;
+pub use parameter::{
+    GadgetRlweParameters, KeySwitchingParameters, LweParameters, NoiseBudgetPlan,
+    NoiseDistributionType, NoiseOperation, NoiseSampler,
+};
 
 pub use public_key::{LwePublicKey, LwePublicKeyRlweMode, NttRlwePublicKey};
 pub use secret_key::{
-    LweSecretKey, LweSecretKeyType, NttRlweSecretKey, RingSecretKeyType, RlweSecretKey,
+    LweSecretKey, LweSecretKeyDistribution, LweSecretKeyType, NttRlweSecretKey, RingSecretKeyType,
+    RlweSecretKey,
 };
 
-pub use ciphertext::{CmLweCiphertext, LweCiphertext, NttRlweCiphertext, RlweCiphertext};
-pub use plaintext::{decode, encode};
+pub use ciphertext::{
+    CmLweCiphertext, LweCiphertext, NttRgswCiphertext, NttRlweCiphertext, RgswCiphertext,
+    RlweCiphertext,
+};
+pub use constant_time::ct_eq;
+pub use plaintext::{decode, decode_f64, encode, encode_f64, encode_with_policy, OverflowPolicy};
 
 pub use blind_rotation::BlindRotationKey;
 pub use key_switch::*;
@@ -42,5 +55,6 @@ pub use automorphism::{AutoKey, AutoSpace};
 pub use trace::TraceKey;
 
 pub use modulus_switch::{
-    lwe_modulus_switch, lwe_modulus_switch_assign, lwe_modulus_switch_inplace,
+    lwe_modulus_switch, lwe_modulus_switch_assign, lwe_modulus_switch_from_native,
+    lwe_modulus_switch_inplace,
 };
@@ -16,10 +16,11 @@ pub fn lwe_modulus_switch<CIn: UnsignedInteger, COut: UnsignedInteger>(
 ) -> LweCiphertext<COut> {
     match modulus_out {
         ModulusValue::Native => lwe_modulus_switch_to_native(c_in, modulus_in),
-        ModulusValue::PowerOf2(modulus_out) => {
-            lwe_modulus_switch_to_pow_of_2(c_in, modulus_in, modulus_out)
+        ModulusValue::PowerOf2(modulus_out)
+        | ModulusValue::Prime(modulus_out)
+        | ModulusValue::Others(modulus_out) => {
+            lwe_modulus_switch_normal(c_in, modulus_in, modulus_out)
         }
-        ModulusValue::Prime(_) | ModulusValue::Others(_) => unimplemented!(),
     }
 }
 
@@ -27,7 +28,7 @@ pub fn lwe_modulus_switch<CIn: UnsignedInteger, COut: UnsignedInteger>(
 ///
 /// This function performs on a [`LweCiphertext<CIn>`],
 /// returns a [`LweCiphertext<COut>`] with desired modulus `modulus_out`.
-pub fn lwe_modulus_switch_to_pow_of_2<CIn: UnsignedInteger, COut: UnsignedInteger>(
+pub fn lwe_modulus_switch_normal<CIn: UnsignedInteger, COut: UnsignedInteger>(
     c_in: &LweCiphertext<CIn>,
     modulus_in: CIn,
     modulus_out: COut,
@@ -76,6 +77,38 @@ pub fn lwe_modulus_switch_to_native<CIn: UnsignedInteger, COut: UnsignedInteger>
     LweCiphertext::new(a, b)
 }
 
+/// Implementation of modulus switching.
+///
+/// This function performs on a [`LweCiphertext<CIn>`] with the native modulus `2^CIn::BITS`,
+/// returns a [`LweCiphertext<COut>`] with desired modulus `modulus_out`.
+pub fn lwe_modulus_switch_from_native<CIn: UnsignedInteger, COut: UnsignedInteger>(
+    c_in: &LweCiphertext<CIn>,
+    modulus_out: ModulusValue<COut>,
+) -> LweCiphertext<COut> {
+    let modulus_in_f64: f64 = 2.0f64.powi(CIn::BITS as i32);
+    let modulus_out_f64: f64 = match modulus_out {
+        ModulusValue::Native => 2.0f64.powi(COut::BITS as i32),
+        ModulusValue::PowerOf2(modulus_out)
+        | ModulusValue::Prime(modulus_out)
+        | ModulusValue::Others(modulus_out) => modulus_out.as_into(),
+    };
+
+    // a value close to the modulus rounds up to the modulus, which is `0`
+    let switch = |v: CIn| {
+        let v = (AsInto::<f64>::as_into(v) * modulus_out_f64 / modulus_in_f64).round();
+        if v < modulus_out_f64 {
+            COut::as_from(v)
+        } else {
+            COut::as_from(v - modulus_out_f64)
+        }
+    };
+
+    let a: Vec<COut> = c_in.a().iter().copied().map(&switch).collect();
+    let b = switch(c_in.b());
+
+    LweCiphertext::new(a, b)
+}
+
 /// Implementation of modulus switching.
 ///
 /// This function performs on a [`LweCiphertext<CIn>`],
@@ -89,10 +122,11 @@ pub fn lwe_modulus_switch_inplace<CIn: UnsignedInteger, COut: UnsignedInteger>(
 ) {
     match modulus_out {
         ModulusValue::Native => lwe_modulus_switch_inplace_to_native(c_in, modulus_in, c_out),
-        ModulusValue::PowerOf2(modulus_out) => {
-            lwe_modulus_switch_inplace_to_pow_of_2(c_in, modulus_in, modulus_out, c_out)
+        ModulusValue::PowerOf2(modulus_out)
+        | ModulusValue::Prime(modulus_out)
+        | ModulusValue::Others(modulus_out) => {
+            lwe_modulus_switch_inplace_normal(c_in, modulus_in, modulus_out, c_out)
         }
-        ModulusValue::Prime(_) | ModulusValue::Others(_) => unimplemented!(),
     }
 }
 
@@ -101,7 +135,7 @@ pub fn lwe_modulus_switch_inplace<CIn: UnsignedInteger, COut: UnsignedInteger>(
 /// This function performs on a [`LweCiphertext<CIn>`],
 /// puts the result [`LweCiphertext<COut>`] with desired modulus `modulus_out`
 /// into `c_out`.
-pub fn lwe_modulus_switch_inplace_to_pow_of_2<CIn: UnsignedInteger, COut: UnsignedInteger>(
+pub fn lwe_modulus_switch_inplace_normal<CIn: UnsignedInteger, COut: UnsignedInteger>(
     c_in: LweCiphertext<CIn>,
     modulus_in: CIn,
     modulus_out: COut,
@@ -0,0 +1,168 @@
+//! Noise estimation for debugging, enabled by the `debug-noise` feature.
+//!
+//! Every method here needs the secret key and branches on secret dependent values,
+//! so they are **not** constant time and must only be used in tests and debugging.
+
+use algebra::{
+    integer::{AsInto, UnsignedInteger},
+    ntt::NumberTheoryTransform,
+    reduce::{ModulusValue, RingReduce},
+    Field, NttField,
+};
+
+use crate::{
+    GadgetRlweParameters, LweCiphertext, LweParameters, LweSecretKey, NttRlweSecretKey,
+    RlweCiphertext,
+};
+
+impl<C: UnsignedInteger, Modulus: RingReduce<C>> LweParameters<C, Modulus> {
+    /// Returns `log2(|e| / (q / 2t))` of a [`LweCiphertext<C>`],
+    /// where `e` is the noise and `q / 2t` is the decryption threshold.
+    ///
+    /// The result is negative while the ciphertext still decrypts correctly,
+    /// and `-inf` when the noise is zero.
+    ///
+    /// It is **not** constant time, only use it for tests and debugging.
+    pub fn noise_bits(&self, ciphertext: &LweCiphertext<C>, secret_key: &LweSecretKey<C>) -> f64 {
+        let modulus = self.cipher_modulus;
+
+        let a_mul_s = modulus.reduce_dot_product(ciphertext.a(), secret_key);
+        let plaintext = modulus.reduce_sub(ciphertext.b(), a_mul_s);
+
+        let q = match self.cipher_modulus_value {
+            ModulusValue::Native => 2.0f64.powi(C::BITS as i32),
+            ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+                q.as_into()
+            }
+        };
+
+        noise_bits(
+            core::iter::once(plaintext.as_into()),
+            q,
+            self.plain_modulus_value.as_into(),
+        )
+    }
+}
+
+impl<F: NttField> GadgetRlweParameters<F> {
+    /// Returns `log2(max|e_i| / (Q / 2t))` of a [`RlweCiphertext<F>`],
+    /// where `e_i` are the noise coefficients and `Q / 2t` is the decryption threshold.
+    ///
+    /// The result is negative while the ciphertext still decrypts correctly,
+    /// and `-inf` when the noise is zero.
+    ///
+    /// It is **not** constant time, only use it for tests and debugging.
+    pub fn noise_bits(
+        &self,
+        ciphertext: &RlweCiphertext<F>,
+        secret_key: &NttRlweSecretKey<F>,
+        plain_modulus: <F as Field>::ValueT,
+        ntt_table: &<F as NttField>::Table,
+    ) -> f64 {
+        let plaintext = self.decrypt_poly(secret_key, ciphertext, ntt_table);
+
+        noise_bits(
+            plaintext
+                .copied_iter()
+                .map(|v| AsInto::<f64>::as_into(F::to_canonical(v))),
+            F::MODULUS_VALUE.as_into(),
+            plain_modulus.as_into(),
+        )
+    }
+}
+
+/// Computes `log2(max|e_i| / (q / 2t))` for noisy encoded values `e_i + round(m_i * q / t)`.
+fn noise_bits(plaintexts: impl Iterator<Item = f64>, q: f64, t: f64) -> f64 {
+    let delta = q / t;
+
+    let max_noise = plaintexts
+        .map(|v| (v - (v / delta).round() * delta).abs())
+        .fold(0.0f64, f64::max);
+
+    max_noise.log2() - (delta / 2.0).log2()
+}
+
+#[cfg(test)]
+mod tests {
+    use algebra::{
+        decompose::NonPowOf2ApproxSignedBasis, modulus::PowOf2Modulus, polynomial::FieldPolynomial,
+        U32FieldEval,
+    };
+    use rand::{distributions::Uniform, prelude::Distribution};
+
+    use crate::{LweSecretKeyType, NoiseDistributionType, RingSecretKeyType};
+
+    use super::*;
+
+    type FieldT = U32FieldEval<132120577>;
+    type ValT = u32;
+    type PolyT = FieldPolynomial<FieldT>;
+
+    const CIPHER_MODULUS: ValT = FieldT::MODULUS_VALUE;
+    const PLAIN_MODULUS: ValT = 8;
+
+    const N: usize = 1024;
+
+    #[inline]
+    fn encode(m: ValT) -> ValT {
+        (m as f64 * CIPHER_MODULUS as f64 / PLAIN_MODULUS as f64).round() as ValT
+    }
+
+    #[test]
+    fn test_lwe_noise_bits() {
+        let mut rng = rand::thread_rng();
+
+        let params = LweParameters::new_unchecked(
+            512,
+            4u16,
+            PowOf2Modulus::<u16>::new(1 << 14),
+            LweSecretKeyType::Binary,
+            3.20,
+        );
+        let sk = LweSecretKey::generate(&params, &mut rng);
+
+        let c = params.encrypt(&sk, 1u8, &mut rng);
+        assert!(params.noise_bits(&c, &sk) < 0.0);
+    }
+
+    #[test]
+    fn test_rlwe_noise_bits_after_additions() {
+        const ADDITIONS: usize = 16;
+
+        let mut rng = rand::thread_rng();
+        let distr = Uniform::new(0, PLAIN_MODULUS);
+
+        let params = GadgetRlweParameters::<FieldT> {
+            dimension: N,
+            modulus: CIPHER_MODULUS,
+            secret_key_type: RingSecretKeyType::Ternary,
+            noise_standard_deviation: 3.20,
+            basis: NonPowOf2ApproxSignedBasis::new(CIPHER_MODULUS, 4, None),
+            noise_distribution_type: NoiseDistributionType::Gaussian,
+        };
+        let ntt_table = params.generate_ntt_table().unwrap();
+
+        let sk = params.generate_secret_key(&mut rng);
+        let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+
+        let mut encrypt = || {
+            let values = PolyT::new(distr.sample_iter(&mut rng).take(N).map(encode).collect());
+            params.encrypt_poly(&ntt_sk, &values, &ntt_table, &mut rng)
+        };
+
+        let fresh = encrypt();
+        let fresh_bits = params.noise_bits(&fresh, &ntt_sk, PLAIN_MODULUS, &ntt_table);
+        assert!(fresh_bits < 0.0);
+
+        let mut sum = fresh;
+        for _ in 1..ADDITIONS {
+            sum.add_assign_element_wise(&encrypt());
+        }
+        let sum_bits = params.noise_bits(&sum, &ntt_sk, PLAIN_MODULUS, &ntt_table);
+        assert!(sum_bits < 0.0);
+
+        // The noise standard deviation grows by `sqrt(ADDITIONS) = 4`, about 2 bits.
+        let growth = sum_bits - fresh_bits;
+        assert!((1.0..3.0).contains(&growth), "noise grows {growth} bits");
+    }
+}
@@ -1,4 +1,13 @@
-use algebra::{integer::UnsignedInteger, random::DiscreteGaussian, Field};
+use algebra::{
+    integer::UnsignedInteger,
+    random::DiscreteGaussian,
+    reduce::{ModulusValue, RingReduce},
+    Field,
+};
+use num_traits::ConstOne;
+use rand::{CryptoRng, Rng};
+
+use crate::{LweCiphertext, LweParameters, LweSecretKey, ParameterError, PowOf2LweKeySwitchingKey};
 
 /// Represents the parameters used for key switching in cryptographic schemes.
 #[derive(Debug, Clone, Copy)]
@@ -18,6 +27,77 @@ pub struct KeySwitchingParameters {
 }
 
 impl KeySwitchingParameters {
+    /// Creates the [`KeySwitchingParameters`] switching ciphertexts of `from` to ciphertexts of `to`.
+    ///
+    /// The cipher modulus is decomposed into `levels` digits of `log_basis` bits,
+    /// keeping the most significant bits when they do not cover the whole modulus.
+    /// The key switching key uses the noise standard deviation of `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParameterError`] if
+    /// - a dimension is zero,
+    /// - the cipher moduli of `from` and `to` differ,
+    /// - the cipher modulus is neither native nor a power of 2,
+    /// - `log_basis` or `levels` is zero, or `levels * log_basis` exceeds the log modulus.
+    pub fn for_switch<C, Modulus>(
+        from: &LweParameters<C, Modulus>,
+        to: &LweParameters<C, Modulus>,
+        log_basis: u32,
+        levels: usize,
+    ) -> Result<Self, ParameterError>
+    where
+        C: UnsignedInteger,
+        Modulus: RingReduce<C>,
+    {
+        for dimension in [from.dimension, to.dimension] {
+            if dimension == 0 {
+                return Err(ParameterError::DimensionNotValid(dimension));
+            }
+        }
+
+        if from.cipher_modulus_value != to.cipher_modulus_value {
+            return Err(ParameterError::ModulusMismatch {
+                from: format!("{:?}", from.cipher_modulus_value),
+                to: format!("{:?}", to.cipher_modulus_value),
+            });
+        }
+
+        let modulus_value = to.cipher_modulus_value;
+        if !matches!(
+            modulus_value,
+            ModulusValue::Native | ModulusValue::PowerOf2(_)
+        ) {
+            return Err(ParameterError::ModulusNotValid(format!(
+                "{:?}",
+                modulus_value
+            )));
+        }
+
+        let log_modulus = modulus_value.log_modulus();
+        let full_length = if log_basis == 0 {
+            0
+        } else {
+            (log_modulus / log_basis) as usize
+        };
+        if levels == 0 || levels > full_length {
+            return Err(ParameterError::DecompositionNotValid {
+                log_basis,
+                levels,
+                log_modulus,
+            });
+        }
+
+        Ok(Self {
+            input_cipher_dimension: from.dimension,
+            output_cipher_dimension: to.dimension,
+            log_modulus,
+            log_basis,
+            reverse_length: (levels < full_length).then_some(levels),
+            noise_standard_deviation: to.noise_standard_deviation,
+        })
+    }
+
     /// Gets the discrete gaussian noise distribution.
     #[inline]
     pub fn noise_distribution_for_q<C: UnsignedInteger>(
@@ -31,6 +111,51 @@ impl KeySwitchingParameters {
     #[allow(non_snake_case)]
     #[inline]
     pub fn noise_distribution_for_Q<Q: Field>(&self) -> DiscreteGaussian<<Q as Field>::ValueT> {
-        DiscreteGaussian::new(0.0, self.noise_standard_deviation, Q::MINUS_ONE).unwrap()
+        DiscreteGaussian::new(
+            0.0,
+            self.noise_standard_deviation,
+            Q::MODULUS_VALUE - <<Q as Field>::ValueT as ConstOne>::ONE,
+        )
+        .unwrap()
+    }
+
+    /// Generates a [`PowOf2LweKeySwitchingKey<C>`] which switches ciphertexts
+    /// under `from_sk` to ciphertexts under `to_sk`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimensions of the secret keys do not match these parameters.
+    #[inline]
+    pub fn generate_key<C, CIn, R>(
+        &self,
+        from_sk: &LweSecretKey<CIn>,
+        to_sk: &LweSecretKey<C>,
+        modulus: impl RingReduce<C>,
+        rng: &mut R,
+    ) -> PowOf2LweKeySwitchingKey<C>
+    where
+        C: UnsignedInteger,
+        CIn: UnsignedInteger,
+        R: Rng + CryptoRng,
+    {
+        assert_eq!(from_sk.dimension(), self.input_cipher_dimension);
+        assert_eq!(to_sk.dimension(), self.output_cipher_dimension);
+        PowOf2LweKeySwitchingKey::generate(from_sk, to_sk, *self, modulus, rng)
+    }
+
+    /// Switches `ciphertext` to a [`LweCiphertext<C>`] under the output secret key of `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `ciphertext` is not equal to the input cipher dimension.
+    #[inline]
+    pub fn key_switch<C: UnsignedInteger>(
+        &self,
+        key: &PowOf2LweKeySwitchingKey<C>,
+        ciphertext: &LweCiphertext<C>,
+        modulus: impl RingReduce<C>,
+    ) -> LweCiphertext<C> {
+        assert_eq!(ciphertext.a().len(), self.input_cipher_dimension);
+        key.key_switch(ciphertext, modulus)
     }
 }
@@ -1,10 +1,27 @@
+#[cfg(feature = "parallel")]
+use algebra::random::Prg;
 use algebra::{
-    integer::UnsignedInteger,
-    random::DiscreteGaussian,
+    integer::{AsInto, UnsignedInteger},
+    modulus::PowOf2Modulus,
+    random::NoiseDistribution,
     reduce::{ModulusValue, RingReduce},
 };
+#[cfg(feature = "parallel")]
+use rand::SeedableRng;
+use rand::{CryptoRng, Rng};
 
-use crate::LweSecretKeyType;
+use crate::{
+    encode, encode_with_policy, lwe_modulus_switch, lwe_modulus_switch_from_native, FHECoreError,
+    LweCiphertext, LweSecretKey, LweSecretKeyDistribution, LweSecretKeyType, NoiseBudgetPlan,
+    NoiseDistributionType, NoiseSampler, OverflowPolicy, ParameterError,
+};
+
+/// The smallest noise standard deviation accepted by `validate`.
+/// A discrete gaussian noise with smaller deviation is mostly zero.
+pub(crate) const MIN_NOISE_STANDARD_DEVIATION: f64 = 1.0;
+
+/// The largest log2 probability of a fresh ciphertext failing to decrypt accepted by `validate`.
+pub(crate) const MAX_DECRYPTION_FAILURE_LOG2: f64 = -40.0;
 
 /// Lwe Parameters.
 #[derive(Debug, Clone, Copy)]
@@ -23,14 +40,19 @@ pub struct LweParameters<LweValue: UnsignedInteger, LweModulus: RingReduce<LweVa
     pub secret_key_type: LweSecretKeyType,
     /// **LWE** noise error's standard deviation.
     pub noise_standard_deviation: f64,
+    /// The distribution type of the **LWE** noise error.
+    pub noise_distribution_type: NoiseDistributionType,
 }
 
 impl<LweValue: UnsignedInteger, LweModulus: RingReduce<LweValue>>
     LweParameters<LweValue, LweModulus>
 {
-    /// Creates a new [`LweParameters<LweValue, LweModulus>`].
+    /// Creates a new [`LweParameters<LweValue, LweModulus>`] with gaussian noise without any check.
+    ///
+    /// Nothing prevents insecure or inconsistent parameters here,
+    /// use [`Self::try_new`] unless they have been checked otherwise.
     #[inline]
-    pub fn new(
+    pub fn new_unchecked(
         dimension: usize,
         plain_modulus_value: LweValue,
         cipher_modulus: LweModulus,
@@ -47,7 +69,62 @@ impl<LweValue: UnsignedInteger, LweModulus: RingReduce<LweValue>>
             cipher_modulus,
             secret_key_type,
             noise_standard_deviation,
+            noise_distribution_type: NoiseDistributionType::Gaussian,
+        }
+    }
+
+    /// Creates a new [`LweParameters<LweValue, LweModulus>`] without any check.
+    #[deprecated(note = "use `try_new`, or `new_unchecked` for parameters checked otherwise")]
+    #[inline]
+    pub fn new(
+        dimension: usize,
+        plain_modulus_value: LweValue,
+        cipher_modulus: LweModulus,
+        secret_key_type: LweSecretKeyType,
+        noise_standard_deviation: f64,
+    ) -> Self {
+        Self::new_unchecked(
+            dimension,
+            plain_modulus_value,
+            cipher_modulus,
+            secret_key_type,
+            noise_standard_deviation,
+        )
+    }
+
+    /// Creates a new [`LweParameters<LweValue, LweModulus>`] which passes [`Self::validate`]
+    /// and whose [`Self::estimated_security_bits`] is at least `security_bits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`Self::validate`], or
+    /// [`ParameterError::SecurityTooLow`] if the estimated security is too low.
+    pub fn try_new(
+        dimension: usize,
+        plain_modulus_value: LweValue,
+        cipher_modulus: LweModulus,
+        secret_key_type: LweSecretKeyType,
+        noise_standard_deviation: f64,
+        security_bits: f64,
+    ) -> Result<Self, ParameterError> {
+        let params = Self::new_unchecked(
+            dimension,
+            plain_modulus_value,
+            cipher_modulus,
+            secret_key_type,
+            noise_standard_deviation,
+        );
+        params.validate()?;
+
+        let estimated = params.estimated_security_bits();
+        if estimated < security_bits {
+            return Err(ParameterError::SecurityTooLow {
+                estimated,
+                required: security_bits,
+            });
         }
+
+        Ok(params)
     }
 
     /// Returns the dimension of this [`LweParameters<LweValue, LweModulus>`].
@@ -92,14 +169,474 @@ impl<LweValue: UnsignedInteger, LweModulus: RingReduce<LweValue>>
         self.noise_standard_deviation
     }
 
-    /// Gets the discrete gaussian noise distribution.
+    /// Returns the noise distribution type of this [`LweParameters<LweValue, LweModulus>`].
+    #[inline]
+    pub fn noise_distribution_type(&self) -> NoiseDistributionType {
+        self.noise_distribution_type
+    }
+
+    /// Returns the log2 of the estimated probability that a fresh ciphertext fails to decrypt.
+    ///
+    /// Decryption fails when the noise `|e|` reaches `q/2t`, which is bounded by the
+    /// gaussian tail `P(|e| ≥ x) ≤ 2·exp(-x²/2σ²)` with the deviation `σ` of
+    /// [`Self::noise_distribution`].
+    pub fn decryption_failure_log2_probability(&self) -> f64 {
+        let x = self.decryption_threshold();
+        let sigma = self.noise_distribution().standard_deviation();
+
+        (1.0 - x * x / (2.0 * sigma * sigma) * core::f64::consts::LOG2_E).min(0.0)
+    }
+
+    /// Returns the estimated probability that a fresh ciphertext fails to decrypt
+    /// after the homomorphic operations of `plan`.
+    ///
+    /// The noise is modeled as a centered gaussian whose variance is the variance `σ²`
+    /// of [`Self::noise_distribution`] scaled by
+    /// [`NoiseBudgetPlan::variance_factor`], and decryption fails when `|e|` reaches `q/2t`,
+    /// so the result is `erfc(q / (2t·σ'·√2))` with the final deviation `σ'`.
+    /// It only counts the noise of the ciphertexts, not a wrap around of the messages mod `t`.
+    ///
+    /// Probabilities too small for [`f64`] underflow to `0`.
+    pub fn failure_probability(&self, plan: &NoiseBudgetPlan) -> f64 {
+        let x = self.decryption_threshold();
+        let sigma = self.noise_distribution().standard_deviation() * plan.variance_factor().sqrt();
+
+        if sigma == 0.0 {
+            return 0.0;
+        }
+        erfc(x / (sigma * core::f64::consts::SQRT_2))
+    }
+
+    /// Returns the decryption threshold `q/2t` as [`f64`].
+    fn decryption_threshold(&self) -> f64 {
+        let q: f64 = match self.cipher_modulus_value {
+            ModulusValue::Native => 2.0f64.powi(LweValue::BITS as i32),
+            ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+                q.as_into()
+            }
+        };
+        let t: f64 = self.plain_modulus_value.as_into();
+
+        q / (2.0 * t)
+    }
+
+    /// Returns a heuristic estimate of the classical security level in bits.
+    ///
+    /// It only considers the primal attack, solving unique SVP in the embedding lattice
+    /// with BKZ, with the success condition of the 2016 estimate and
+    /// the cost `8d · 2^(0.292β + 16.4)` of BKZ-β in dimension `d`.
+    /// The small secret is taken into account by rescaling its part of the lattice.
+    ///
+    /// This is a quick sanity bound, not a substitute for the lattice estimator.
+    pub fn estimated_security_bits(&self) -> f64 {
+        let log_q: f64 = match self.cipher_modulus_value {
+            ModulusValue::Native => LweValue::BITS as f64,
+            ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+                AsInto::<f64>::as_into(q).log2()
+            }
+        };
+        let secret_standard_deviation = self.secret_key_distribution().variance().sqrt();
+
+        primal_usvp_security_bits(
+            self.dimension,
+            log_q,
+            self.noise_distribution().standard_deviation(),
+            secret_standard_deviation,
+        )
+    }
+
+    /// Checks that these parameters are consistent.
+    ///
+    /// This is a sanity check rather than a security estimate. It rejects
+    /// - zero dimension,
+    /// - plain modulus not in `[2, q)`,
+    /// - gaussian noise standard deviation less than 1 or not finite,
+    /// - an estimated decryption failure probability of fresh ciphertexts larger than `2⁻⁴⁰`.
+    pub fn validate(&self) -> Result<(), ParameterError> {
+        if self.dimension == 0 {
+            return Err(ParameterError::DimensionNotValid(self.dimension));
+        }
+
+        let q_minus_one = self.cipher_modulus_value.modulus_minus_one();
+        if let ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) =
+            self.cipher_modulus_value
+        {
+            if q <= LweValue::ONE {
+                return Err(ParameterError::ModulusNotValid(q.to_string()));
+            }
+        }
+
+        if self.plain_modulus_value <= LweValue::ONE || self.plain_modulus_value > q_minus_one {
+            return Err(ParameterError::PlainModulusNotValid {
+                plain_modulus: self.plain_modulus_value.to_string(),
+                cipher_modulus: format!("{:?}", self.cipher_modulus_value),
+            });
+        }
+
+        let sigma = self.noise_standard_deviation;
+        if self.noise_distribution_type == NoiseDistributionType::Gaussian
+            && (!sigma.is_finite() || sigma < MIN_NOISE_STANDARD_DEVIATION)
+        {
+            return Err(ParameterError::NoiseNotValid(sigma));
+        }
+
+        let failure = self.decryption_failure_log2_probability();
+        if failure > MAX_DECRYPTION_FAILURE_LOG2 {
+            return Err(ParameterError::DecryptionFailureTooLikely(failure));
+        }
+
+        Ok(())
+    }
+
+    /// Gets the sampler of the secret key coefficients.
+    #[inline]
+    pub fn secret_key_distribution(&self) -> LweSecretKeyDistribution<LweValue> {
+        LweSecretKeyDistribution::new(self.secret_key_type, self.cipher_modulus_minus_one)
+    }
+
+    /// Gets the sampler of the noise errors, of [`Self::noise_distribution_type`].
     #[inline]
-    pub fn noise_distribution(&self) -> DiscreteGaussian<LweValue> {
-        DiscreteGaussian::new(
-            0.0,
+    pub fn noise_distribution(&self) -> NoiseSampler<LweValue> {
+        NoiseSampler::new(
+            self.noise_distribution_type,
             self.noise_standard_deviation,
             self.cipher_modulus_minus_one,
         )
-        .unwrap()
     }
+
+    /// Encrypts `message` into [`LweCiphertext<LweValue>`] under `secret_key`
+    /// with the cipher modulus and noise distribution of these parameters.
+    #[inline]
+    pub fn encrypt<Msg, R>(
+        &self,
+        secret_key: &LweSecretKey<LweValue>,
+        message: Msg,
+        rng: &mut R,
+    ) -> LweCiphertext<LweValue>
+    where
+        Msg: TryInto<LweValue>,
+        R: Rng + CryptoRng,
+    {
+        secret_key.encrypt(message, self, rng)
+    }
+
+    /// Encrypts `message` into the noiseless [`LweCiphertext<LweValue>`] `(0, encode(message))`,
+    /// which decrypts to `message` under every secret key.
+    ///
+    /// It is not secure, it represents a public constant, for example to add it to a ciphertext
+    /// without increasing the noise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `message` does not fit in the plaintext space.
+    #[inline]
+    pub fn trivial_encrypt<Msg>(&self, message: Msg) -> LweCiphertext<LweValue>
+    where
+        Msg: TryInto<LweValue>,
+    {
+        LweCiphertext::new(
+            vec![LweValue::ZERO; self.dimension],
+            encode(message, self.plain_modulus_value, self.cipher_modulus_value),
+        )
+    }
+
+    /// Encrypts every message of `messages` into [`LweCiphertext<LweValue>`]
+    /// under `secret_key`.
+    ///
+    /// With the `parallel` feature the messages are encrypted on the rayon thread pool,
+    /// each ciphertext samples from its own [`Prg`](algebra::random::Prg) seeded by `rng`.
+    pub fn encrypt_batch<Msg, R>(
+        &self,
+        secret_key: &LweSecretKey<LweValue>,
+        messages: &[Msg],
+        rng: &mut R,
+    ) -> Vec<LweCiphertext<LweValue>>
+    where
+        Msg: Copy + Send + Sync + TryInto<LweValue>,
+        R: Rng + CryptoRng,
+    {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+
+            let prgs: Vec<Prg> = messages
+                .iter()
+                .map(|_| Prg::from_rng(&mut *rng).unwrap())
+                .collect();
+
+            messages
+                .par_iter()
+                .zip(prgs)
+                .map(|(&message, mut prg)| secret_key.encrypt(message, self, &mut prg))
+                .collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            messages
+                .iter()
+                .map(|&message| secret_key.encrypt(message, self, rng))
+                .collect()
+        }
+    }
+
+    /// Decrypts `ciphertext` under `secret_key` back to message,
+    /// rounding away the noise.
+    #[inline]
+    pub fn decrypt<Msg>(
+        &self,
+        secret_key: &LweSecretKey<LweValue>,
+        ciphertext: &LweCiphertext<LweValue>,
+    ) -> Msg
+    where
+        Msg: TryFrom<LweValue>,
+    {
+        secret_key.decrypt(ciphertext, self)
+    }
+
+    /// Encrypts a real `value` with `scale` into [`LweCiphertext<LweValue>`],
+    /// see [`LweSecretKey::encrypt_f64`].
+    #[inline]
+    pub fn encrypt_f64<R>(
+        &self,
+        secret_key: &LweSecretKey<LweValue>,
+        value: f64,
+        scale: f64,
+        rng: &mut R,
+    ) -> LweCiphertext<LweValue>
+    where
+        R: Rng + CryptoRng,
+    {
+        secret_key.encrypt_f64(value, scale, self, rng)
+    }
+
+    /// Decrypts `ciphertext` under `secret_key` back to a real value,
+    /// see [`LweSecretKey::decrypt_f64`].
+    #[inline]
+    pub fn decrypt_f64(
+        &self,
+        secret_key: &LweSecretKey<LweValue>,
+        ciphertext: &LweCiphertext<LweValue>,
+        scale: f64,
+    ) -> f64 {
+        secret_key.decrypt_f64(ciphertext, scale, self)
+    }
+
+    /// Returns `true` if `message` lies in the plaintext space `[0, t)`.
+    #[inline]
+    pub fn fits_plaintext(&self, message: LweValue) -> bool {
+        message < self.plain_modulus_value
+    }
+
+    /// Encodes `message` into the cipher modulus,
+    /// handling a message outside the plaintext space according to `policy`,
+    /// see [`encode_with_policy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FHECoreError::MessageOutOfRange`] if `message` does not fit in the plaintext space
+    /// and `policy` is [`OverflowPolicy::Error`].
+    #[inline]
+    pub fn encode_with_policy(
+        &self,
+        message: LweValue,
+        policy: OverflowPolicy,
+    ) -> Result<LweValue, FHECoreError> {
+        encode_with_policy(
+            message,
+            self.plain_modulus_value,
+            self.cipher_modulus_value,
+            policy,
+        )
+    }
+
+    /// Calculates `a + b (mod t)` in the plaintext space.
+    ///
+    /// This is the message decrypted from the sum of the encryptions of `a` and `b`,
+    /// as long as the summed noise stays below `q/2t`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` does not fit in the plaintext space.
+    #[inline]
+    pub fn plain_add(&self, a: LweValue, b: LweValue) -> LweValue {
+        assert!(self.fits_plaintext(a) && self.fits_plaintext(b));
+
+        let t = self.plain_modulus_value;
+        if a >= t - b {
+            a - (t - b)
+        } else {
+            a + b
+        }
+    }
+
+    /// Calculates `a * b (mod t)` in the plaintext space.
+    ///
+    /// This is the message decrypted from the encryption of `a` multiplied by the scalar `b`,
+    /// as long as `t` divides `q` and the scaled noise stays below `q/2t`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` does not fit in the plaintext space.
+    #[inline]
+    pub fn plain_mul(&self, a: LweValue, b: LweValue) -> LweValue {
+        assert!(self.fits_plaintext(a) && self.fits_plaintext(b));
+
+        let t: u128 = self.plain_modulus_value.as_into();
+        let product = AsInto::<u128>::as_into(a) * AsInto::<u128>::as_into(b);
+        LweValue::as_from(product % t)
+    }
+
+    /// Calculates the homomorphic linear combination `Σ cᵢ·ctᵢ` of `terms = [(cᵢ, ctᵢ)]`.
+    ///
+    /// It decrypts to `Σ cᵢ·mᵢ (mod t)` as long as `t` divides `q` and the noise,
+    /// predicted by [`Self::linear_combine_noise_standard_deviation`], stays below `q/2t`.
+    /// An empty `terms` gives the noiseless encryption of `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of a ciphertext is not equal to the dimension.
+    pub fn linear_combine(
+        &self,
+        terms: &[(LweValue, &LweCiphertext<LweValue>)],
+    ) -> LweCiphertext<LweValue> {
+        let mut result = LweCiphertext::zero(self.dimension);
+        for &(scalar, ciphertext) in terms {
+            assert_eq!(ciphertext.dimension(), self.dimension);
+            result.add_assign_rhs_mul_scalar_reduce(ciphertext, scalar, self.cipher_modulus);
+        }
+        result
+    }
+
+    /// Returns the noise standard deviation of [`Self::linear_combine`] of fresh ciphertexts
+    /// with `scalars`, that is `σ·√(Σ cᵢ²)` where `cᵢ` is the centered lift of the scalar.
+    pub fn linear_combine_noise_standard_deviation(&self, scalars: &[LweValue]) -> f64 {
+        let q_minus_one = self.cipher_modulus_minus_one;
+        let squares: f64 = scalars
+            .iter()
+            .map(|&c| {
+                let magnitude: f64 = if c > q_minus_one >> 1u32 {
+                    AsInto::<f64>::as_into(q_minus_one - c) + 1.0
+                } else {
+                    c.as_into()
+                };
+                magnitude * magnitude
+            })
+            .sum();
+        self.noise_distribution().standard_deviation() * squares.sqrt()
+    }
+
+    /// Switches `ciphertext` from the cipher modulus of these parameters to `modulus_out`,
+    /// rounding every component as `round(q' / q * x)`.
+    ///
+    /// The native modulus is `q = 2^LweValue::BITS`.
+    #[inline]
+    pub fn modulus_switch<COut: UnsignedInteger>(
+        &self,
+        ciphertext: &LweCiphertext<LweValue>,
+        modulus_out: ModulusValue<COut>,
+    ) -> LweCiphertext<COut> {
+        match self.cipher_modulus_value {
+            ModulusValue::Native => lwe_modulus_switch_from_native(ciphertext, modulus_out),
+            ModulusValue::PowerOf2(modulus_in)
+            | ModulusValue::Prime(modulus_in)
+            | ModulusValue::Others(modulus_in) => {
+                lwe_modulus_switch(ciphertext, modulus_in, modulus_out)
+            }
+        }
+    }
+}
+
+impl LweParameters<u16, PowOf2Modulus<u16>> {
+    /// The LWE parameters of a TFHE like scheme
+    /// with at least 128 bits by [`Self::estimated_security_bits`], about 135.
+    ///
+    /// They are the LWE part of `DEFAULT_128_BITS_PARAMETERS` in `boolean_fhe`.
+    /// The security level is the primal uSVP estimate of [`Self::estimated_security_bits`],
+    /// not a run of the lattice estimator.
+    ///
+    /// - dimension **n** = 560, binary secret key,
+    /// - cipher modulus **q** = 2¹⁴,
+    /// - plain modulus **t** = 4, so that the plaintext space is `ℤ₄`,
+    /// - noise standard deviation σ = 3.20.
+    #[inline]
+    pub fn preset_128bit_tfhe() -> Self {
+        Self::new_unchecked(
+            560,
+            4,
+            PowOf2Modulus::<u16>::new(1 << 14),
+            LweSecretKeyType::Binary,
+            3.20,
+        )
+    }
+}
+
+/// The complementary error function `erfc(x) = 1 - erf(x)` of `x ≥ 0`,
+/// with a relative error below `1.2·10⁻⁷`.
+///
+/// See the Chebyshev fitting in *Numerical Recipes*, section 6.2.
+fn erfc(x: f64) -> f64 {
+    debug_assert!(x >= 0.0);
+
+    let t = 1.0 / (1.0 + 0.5 * x);
+    let poly = [
+        -1.26551223,
+        1.00002368,
+        0.37409196,
+        0.09678418,
+        -0.18628806,
+        0.27886807,
+        -1.13520398,
+        1.48851587,
+        -0.82215223,
+        0.17087277,
+    ]
+    .iter()
+    .rev()
+    .fold(0.0, |acc, &c| acc * t + c);
+
+    t * (-x * x + poly).exp()
+}
+
+/// The log2 of the root Hermite factor reached by BKZ with block size `beta`.
+fn log2_root_hermite_factor(beta: f64) -> f64 {
+    use core::f64::consts::{E, PI};
+
+    ((PI * beta).log2() / beta + (beta / (2.0 * PI * E)).log2()) / (2.0 * (beta - 1.0))
+}
+
+/// Estimates the classical security bits of the primal uSVP attack against LWE with
+/// dimension `n`, modulus `2^log_q`, noise deviation `sigma` and secret deviation `secret_sigma`.
+///
+/// For every block size `β`, the attack succeeds with `m` samples when
+/// `σ·√β ≤ δ^(2β - d - 1) · (q^m · ν^n)^(1/d)`, where `d = m + n + 1`,
+/// `δ` is the root Hermite factor of BKZ-β and `ν = σ / secret_sigma` rescales the secret.
+pub(crate) fn primal_usvp_security_bits(
+    n: usize,
+    log_q: f64,
+    sigma: f64,
+    secret_sigma: f64,
+) -> f64 {
+    let log_nu = (sigma / secret_sigma).log2().max(0.0);
+    let lhs_base = sigma.log2();
+    let m_step = (n / 64).max(1);
+
+    let succeeds = |beta: usize| {
+        let log_delta = log2_root_hermite_factor(beta as f64);
+        let lhs = lhs_base + 0.5 * (beta as f64).log2();
+        (1..=2 * n).step_by(m_step).find_map(|m| {
+            let d = m + n + 1;
+            let rhs = (2 * beta) as f64 - d as f64 - 1.0;
+            let rhs = rhs * log_delta + (m as f64 * log_q + n as f64 * log_nu) / d as f64;
+            (lhs <= rhs).then_some(d)
+        })
+    };
+
+    // `log2_root_hermite_factor` is only meaningful from around `β = 50`,
+    // smaller block sizes are cheap anyway.
+    let (beta, d) = (50..=2 * n + 1)
+        .find_map(|beta| succeeds(beta).map(|d| (beta, d)))
+        .unwrap_or((2 * n + 1, 3 * n + 1));
+
+    0.292 * beta as f64 + 16.4 + (8.0 * d as f64).log2()
 }
@@ -1,7 +1,15 @@
 mod key_switching;
 mod lwe;
+mod noise_budget;
+mod noise_distribution;
 mod rlwe;
+#[cfg(feature = "serde")]
+mod serialize;
 
 pub use key_switching::KeySwitchingParameters;
 pub use lwe::LweParameters;
+pub use noise_budget::{NoiseBudgetPlan, NoiseOperation};
+pub use noise_distribution::{NoiseDistributionType, NoiseSampler};
 pub use rlwe::GadgetRlweParameters;
+#[cfg(feature = "serde")]
+pub use serialize::PARAMETERS_FORMAT_VERSION;
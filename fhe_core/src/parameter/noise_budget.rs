@@ -0,0 +1,89 @@
+/// A homomorphic operation on a LWE ciphertext, as seen by the noise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NoiseOperation {
+    /// Adds a fresh ciphertext, the noise variance grows by `σ²`.
+    AddFresh,
+    /// Adds a ciphertext with an independent noise of the same variance as the current one,
+    /// for example the other branch of a balanced addition tree. The noise variance doubles.
+    AddIndependent,
+    /// Multiplies by a plaintext scalar `c`, the noise variance grows by `c²`.
+    MulScalar(i64),
+    /// Adds a noiseless ciphertext, such as the encryption of a public constant.
+    AddConstant,
+}
+
+/// The sequence of homomorphic operations applied to a fresh LWE ciphertext,
+/// used to predict its noise with [`LweParameters::failure_probability`].
+///
+/// ```
+/// use fhe_core::{NoiseBudgetPlan, NoiseOperation};
+///
+/// // `2 * (c0 + c1 + c2)`
+/// let plan = NoiseBudgetPlan::new()
+///     .then(NoiseOperation::AddFresh)
+///     .then(NoiseOperation::AddFresh)
+///     .then(NoiseOperation::MulScalar(2));
+/// assert_eq!(plan.variance_factor(), 12.0);
+/// ```
+///
+/// [`LweParameters::failure_probability`]: crate::LweParameters::failure_probability
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NoiseBudgetPlan {
+    operations: Vec<NoiseOperation>,
+}
+
+impl NoiseBudgetPlan {
+    /// Creates an empty [`NoiseBudgetPlan`], which leaves the fresh ciphertext as it is.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `operation` to the plan.
+    #[inline]
+    pub fn push(&mut self, operation: NoiseOperation) {
+        self.operations.push(operation);
+    }
+
+    /// Returns the plan with `operation` appended.
+    #[inline]
+    pub fn then(mut self, operation: NoiseOperation) -> Self {
+        self.push(operation);
+        self
+    }
+
+    /// Returns the operations of the plan in order.
+    #[inline]
+    pub fn operations(&self) -> &[NoiseOperation] {
+        &self.operations
+    }
+
+    /// Returns the noise variance after all the operations,
+    /// in units of the noise variance `σ²` of a fresh ciphertext.
+    pub fn variance_factor(&self) -> f64 {
+        self.operations
+            .iter()
+            .fold(1.0, |factor, operation| match *operation {
+                NoiseOperation::AddFresh => factor + 1.0,
+                NoiseOperation::AddIndependent => factor * 2.0,
+                NoiseOperation::MulScalar(c) => factor * (c as f64) * (c as f64),
+                NoiseOperation::AddConstant => factor,
+            })
+    }
+}
+
+impl Extend<NoiseOperation> for NoiseBudgetPlan {
+    #[inline]
+    fn extend<I: IntoIterator<Item = NoiseOperation>>(&mut self, iter: I) {
+        self.operations.extend(iter);
+    }
+}
+
+impl FromIterator<NoiseOperation> for NoiseBudgetPlan {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = NoiseOperation>>(iter: I) -> Self {
+        Self {
+            operations: iter.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use algebra::{
+    integer::UnsignedInteger,
+    random::{sample_ternary_values, DiscreteGaussian, NoiseDistribution, TernarySampler},
+};
+use rand::{distributions::Distribution, CryptoRng, Rng};
+
+/// The distribution type of the noise error.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoiseDistributionType {
+    /// Discrete gaussian noise with the noise standard deviation of the parameters.
+    #[default]
+    Gaussian,
+    /// Ternary noise, the noise standard deviation of the parameters is not used.
+    Ternary,
+}
+
+/// The sampler of noise errors.
+///
+/// Negative errors are represented as `q - e`.
+#[derive(Debug, Clone, Copy)]
+pub enum NoiseSampler<C: UnsignedInteger> {
+    /// Discrete gaussian `N(0, σ²)`.
+    Gaussian(DiscreteGaussian<C>),
+    /// `0` with probability `1/2`, `1` and `-1` with probability `1/4` each.
+    Ternary(TernarySampler<C>),
+}
+
+impl<C: UnsignedInteger> NoiseSampler<C> {
+    /// Creates the sampler of `noise_distribution_type` for a cipher modulus `q`,
+    /// given `q - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the noise is gaussian and `noise_standard_deviation` is negative or not finite.
+    #[inline]
+    pub fn new(
+        noise_distribution_type: NoiseDistributionType,
+        noise_standard_deviation: f64,
+        cipher_modulus_minus_one: C,
+    ) -> Self {
+        match noise_distribution_type {
+            NoiseDistributionType::Gaussian => Self::Gaussian(
+                DiscreteGaussian::new(0.0, noise_standard_deviation, cipher_modulus_minus_one)
+                    .unwrap(),
+            ),
+            NoiseDistributionType::Ternary => {
+                Self::Ternary(TernarySampler::new(cipher_modulus_minus_one))
+            }
+        }
+    }
+
+    /// Returns the [`NoiseDistributionType`] of this sampler.
+    #[inline]
+    pub fn noise_distribution_type(&self) -> NoiseDistributionType {
+        match self {
+            Self::Gaussian(_) => NoiseDistributionType::Gaussian,
+            Self::Ternary(_) => NoiseDistributionType::Ternary,
+        }
+    }
+
+    /// Returns the standard deviation of the sampled errors.
+    #[inline]
+    pub fn standard_deviation(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl<C: UnsignedInteger> Distribution<C> for NoiseSampler<C> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> C {
+        match self {
+            Self::Gaussian(s) => s.sample(rng),
+            Self::Ternary(s) => s.sample(rng),
+        }
+    }
+}
+
+impl<C: UnsignedInteger> NoiseDistribution<C> for NoiseSampler<C> {
+    #[inline]
+    fn mean(&self) -> f64 {
+        match self {
+            Self::Gaussian(s) => NoiseDistribution::<C>::mean(s),
+            Self::Ternary(s) => s.mean(),
+        }
+    }
+
+    #[inline]
+    fn variance(&self) -> f64 {
+        match self {
+            Self::Gaussian(s) => NoiseDistribution::<C>::variance(s),
+            Self::Ternary(s) => s.variance(),
+        }
+    }
+
+    #[inline]
+    fn sample_values<R>(&self, length: usize, rng: &mut R) -> Vec<C>
+    where
+        R: Rng + CryptoRng,
+    {
+        match self {
+            Self::Gaussian(s) => s.sample_values(length, rng),
+            Self::Ternary(s) => sample_ternary_values(s.minus_one(), length, rng),
+        }
+    }
+}
@@ -1,6 +1,34 @@
-use algebra::{decompose::NonPowOf2ApproxSignedBasis, random::DiscreteGaussian, Field, NttField};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+};
 
-use crate::RingSecretKeyType;
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis,
+    integer::{ct_select, AsFrom, AsInto, UnsignedInteger},
+    ntt::NumberTheoryTransform,
+    polynomial::{FieldNttPolynomial, FieldPolynomial},
+    random::DiscreteGaussian,
+    reduce::ModulusValue,
+    AlgebraError, Choice, Field, NttField, U32FieldEval,
+};
+use lattice::utils::{NttRlweSpace, PolyDecomposeSpace};
+use num_traits::{ConstOne, ConstZero};
+use rand::{CryptoRng, Rng};
+
+use crate::{
+    decode, encode, AutoKey, LweCiphertext, NoiseDistributionType, NoiseSampler, NttRgswCiphertext,
+    NttRlweSecretKey, ParameterError, RingSecretKeyType, RlweCiphertext, RlweSecretKey, TraceKey,
+};
+
+use super::lwe::{primal_usvp_security_bits, MIN_NOISE_STANDARD_DEVIATION};
+
+/// The tables of [`GadgetRlweParameters::generate_ntt_table`] by table type and `log n`.
+///
+/// The table types are generic over their field, so the type identifies the field.
+static NTT_TABLES: LazyLock<Mutex<HashMap<(TypeId, u32), Arc<dyn Any + Send + Sync>>>> =
+    LazyLock::new(Default::default);
 
 /// Rgsw Parameters.
 #[derive(Debug)]
@@ -15,13 +43,19 @@ pub struct GadgetRlweParameters<Q: NttField> {
     pub noise_standard_deviation: f64,
     /// Decompose basis for `Q`.
     pub basis: NonPowOf2ApproxSignedBasis<<Q as Field>::ValueT>,
+    /// The distribution type of the noise error.
+    pub noise_distribution_type: NoiseDistributionType,
 }
 
 impl<Q: NttField> GadgetRlweParameters<Q> {
-    /// Returns the noise distribution.
+    /// Returns the sampler of the noise errors, of the noise distribution type.
     #[inline]
-    pub fn noise_distribution(&self) -> DiscreteGaussian<<Q as Field>::ValueT> {
-        DiscreteGaussian::new(0.0, self.noise_standard_deviation, Q::MINUS_ONE).unwrap()
+    pub fn noise_distribution(&self) -> NoiseSampler<<Q as Field>::ValueT> {
+        NoiseSampler::new(
+            self.noise_distribution_type,
+            self.noise_standard_deviation,
+            Q::MODULUS_VALUE - <<Q as Field>::ValueT as ConstOne>::ONE,
+        )
     }
 
     /// Returns the decompose basis.
@@ -35,6 +69,591 @@ impl<Q: NttField> GadgetRlweParameters<Q> {
     pub fn dimension(&self) -> usize {
         self.dimension
     }
+
+    /// Checks that these parameters are consistent.
+    ///
+    /// This is a sanity check rather than a security estimate. It rejects
+    /// - dimension not a power of 2 or less than 2,
+    /// - modulus or basis modulus different from the modulus of `Q`,
+    /// - gaussian noise standard deviation less than 1 or not finite.
+    pub fn validate(&self) -> Result<(), ParameterError> {
+        if self.dimension < 2 || !self.dimension.is_power_of_two() {
+            return Err(ParameterError::DimensionNotValid(self.dimension));
+        }
+
+        if self.modulus != Q::MODULUS_VALUE || self.basis.modulus() != Q::MODULUS_VALUE {
+            return Err(ParameterError::ModulusNotValid(self.modulus.to_string()));
+        }
+
+        let sigma = self.noise_standard_deviation;
+        if self.noise_distribution_type == NoiseDistributionType::Gaussian
+            && (!sigma.is_finite() || sigma < MIN_NOISE_STANDARD_DEVIATION)
+        {
+            return Err(ParameterError::NoiseNotValid(sigma));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a heuristic estimate of the classical security level in bits.
+    ///
+    /// Each ring element is seen as `N` samples of LWE in dimension `N`,
+    /// estimated with the primal attack model of [`LweParameters::estimated_security_bits`].
+    /// The algebraic structure of the ring is not taken into account.
+    ///
+    /// [`LweParameters::estimated_security_bits`]: crate::LweParameters::estimated_security_bits
+    pub fn estimated_security_bits(&self) -> f64 {
+        let log_q = AsInto::<f64>::as_into(Q::MODULUS_VALUE).log2();
+        let secret_standard_deviation = match self.secret_key_type {
+            RingSecretKeyType::Binary => 0.5,
+            RingSecretKeyType::Ternary => core::f64::consts::FRAC_1_SQRT_2,
+            RingSecretKeyType::Gaussian => self.noise_standard_deviation,
+        };
+
+        primal_usvp_security_bits(
+            self.dimension,
+            log_q,
+            self.noise_distribution().standard_deviation(),
+            secret_standard_deviation,
+        )
+    }
+
+    /// Returns the NTT table for polynomials of this parameter's dimension.
+    ///
+    /// The table holds the precomputed twiddle factors. It is generated on the first
+    /// call for `Q` and the dimension, later calls share the cached table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AlgebraError`] if `Q` has no primitive `2N`-th root of unity.
+    pub fn generate_ntt_table(&self) -> Result<Arc<<Q as NttField>::Table>, AlgebraError> {
+        let log_n = self.dimension.trailing_zeros();
+        let key = (TypeId::of::<<Q as NttField>::Table>(), log_n);
+
+        let mut tables = NTT_TABLES.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(table) = tables.get(&key).and_then(|t| Arc::clone(t).downcast().ok()) {
+            return Ok(table);
+        }
+
+        let table = Arc::new(Q::generate_ntt_table(log_n)?);
+        tables.insert(key, Arc::clone(&table) as Arc<dyn Any + Send + Sync>);
+        Ok(table)
+    }
+
+    /// Generates a [`RlweSecretKey<Q>`] of this parameter's dimension and secret key type.
+    ///
+    /// A gaussian secret key has the noise standard deviation,
+    /// whatever the noise distribution type.
+    #[inline]
+    pub fn generate_secret_key<R>(&self, rng: &mut R) -> RlweSecretKey<Q>
+    where
+        R: Rng + CryptoRng,
+    {
+        let gaussian = DiscreteGaussian::new(
+            0.0,
+            self.noise_standard_deviation,
+            Q::MODULUS_VALUE - <<Q as Field>::ValueT as ConstOne>::ONE,
+        )
+        .unwrap();
+
+        RlweSecretKey::generate(self.secret_key_type, self.dimension, Some(gaussian), rng)
+    }
+
+    /// Encrypts an already encoded `plaintext` polynomial into [`RlweCiphertext<Q>`],
+    /// such that `b = a * s + e + plaintext`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coefficient count of `plaintext` is not equal to the dimension.
+    #[inline]
+    pub fn encrypt_poly<R>(
+        &self,
+        secret_key: &NttRlweSecretKey<Q>,
+        plaintext: &FieldPolynomial<Q>,
+        ntt_table: &<Q as NttField>::Table,
+        rng: &mut R,
+    ) -> RlweCiphertext<Q>
+    where
+        R: Rng + CryptoRng,
+    {
+        assert_eq!(plaintext.coeff_count(), self.dimension);
+
+        let mut cipher = <RlweCiphertext<Q>>::generate_random_zero_sample(
+            secret_key,
+            self.noise_distribution(),
+            ntt_table,
+            rng,
+        );
+        *cipher.b_mut() += plaintext;
+
+        cipher
+    }
+
+    /// Decrypts `cipher` into the noisy plaintext polynomial `b - a * s`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `cipher` is not equal to the dimension.
+    #[inline]
+    pub fn decrypt_poly(
+        &self,
+        secret_key: &NttRlweSecretKey<Q>,
+        cipher: &RlweCiphertext<Q>,
+        ntt_table: &<Q as NttField>::Table,
+    ) -> FieldPolynomial<Q> {
+        assert_eq!(cipher.dimension(), self.dimension);
+
+        let a_mul_s =
+            ntt_table.inverse_transform_inplace(ntt_table.transform(cipher.a()) * &**secret_key);
+
+        cipher.b() - a_mul_s
+    }
+
+    /// Converts `poly` from coefficients to slot values,
+    /// the evaluations at the primitive `2N`-th roots of unity in the order of `ntt_table`.
+    ///
+    /// Slot-wise multiplication of the result is the negacyclic multiplication of the polynomials.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coefficient count of `poly` is not equal to the dimension.
+    #[inline]
+    pub fn to_slots(
+        &self,
+        poly: &FieldPolynomial<Q>,
+        ntt_table: &<Q as NttField>::Table,
+    ) -> FieldNttPolynomial<Q> {
+        assert_eq!(poly.coeff_count(), self.dimension);
+        ntt_table.transform(poly)
+    }
+
+    /// Converts `slots` back to coefficients, the inverse of [`Self::to_slots`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slot count of `slots` is not equal to the dimension.
+    #[inline]
+    pub fn from_slots(
+        &self,
+        slots: &FieldNttPolynomial<Q>,
+        ntt_table: &<Q as NttField>::Table,
+    ) -> FieldPolynomial<Q> {
+        assert_eq!(slots.coeff_count(), self.dimension);
+        ntt_table.inverse_transform(slots)
+    }
+
+    /// Encrypts `messages` into the coefficients of one [`RlweCiphertext<Q>`],
+    /// each encoded as `round(m * Q / t)` with `t = plain_modulus`.
+    ///
+    /// When there are fewer messages than the dimension,
+    /// the remaining coefficients encrypt `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more messages than the dimension,
+    /// or if a message is not less than `plain_modulus`.
+    #[inline]
+    pub fn encrypt_packed<Msg, R>(
+        &self,
+        secret_key: &NttRlweSecretKey<Q>,
+        messages: &[Msg],
+        plain_modulus: <Q as Field>::ValueT,
+        ntt_table: &<Q as NttField>::Table,
+        rng: &mut R,
+    ) -> RlweCiphertext<Q>
+    where
+        Msg: Copy + TryInto<<Q as Field>::ValueT>,
+        R: Rng + CryptoRng,
+    {
+        assert!(
+            messages.len() <= self.dimension,
+            "{} messages do not fit in dimension {}",
+            messages.len(),
+            self.dimension
+        );
+
+        let cipher_modulus = ModulusValue::Prime(Q::MODULUS_VALUE);
+        let mut plaintext = FieldPolynomial::zero(self.dimension);
+        for (p, &m) in plaintext.iter_mut().zip(messages) {
+            *p = Q::from_canonical(encode(m, plain_modulus, cipher_modulus));
+        }
+
+        self.encrypt_poly(secret_key, &plaintext, ntt_table, rng)
+    }
+
+    /// Decrypts `cipher` into the messages of all its coefficients,
+    /// the inverse of [`Self::encrypt_packed`].
+    ///
+    /// The returned vector always has the dimension as length,
+    /// the coefficients without a message decrypt to `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `cipher` is not equal to the dimension.
+    #[inline]
+    pub fn decrypt_packed<Msg>(
+        &self,
+        secret_key: &NttRlweSecretKey<Q>,
+        cipher: &RlweCiphertext<Q>,
+        plain_modulus: <Q as Field>::ValueT,
+        ntt_table: &<Q as NttField>::Table,
+    ) -> Vec<Msg>
+    where
+        Msg: TryFrom<<Q as Field>::ValueT>,
+    {
+        let cipher_modulus = ModulusValue::Prime(Q::MODULUS_VALUE);
+        self.decrypt_poly(secret_key, cipher, ntt_table)
+            .into_iter()
+            .map(|c| decode(Q::to_canonical(c), plain_modulus, cipher_modulus))
+            .collect()
+    }
+
+    /// Encrypts `bit` into [`NttRgswCiphertext<Q>`] with the basis of these parameters.
+    ///
+    /// The two rows are gadget RLWE encryptions of `-s·bit` and `bit`.
+    /// It does not branch on `bit`: an encryption of `0` is generated
+    /// and `bit·G` is added to it through a mask.
+    #[inline]
+    pub fn encrypt_rgsw_bit<R>(
+        &self,
+        secret_key: &NttRlweSecretKey<Q>,
+        bit: bool,
+        ntt_table: &<Q as NttField>::Table,
+        rng: &mut R,
+    ) -> NttRgswCiphertext<Q>
+    where
+        R: Rng + CryptoRng,
+    {
+        let mut rgsw = NttRgswCiphertext::generate_random_zero_sample(
+            secret_key,
+            &self.basis,
+            self.noise_distribution(),
+            ntt_table,
+            rng,
+        );
+
+        // `bit·B^i` is the same value in every ntt slot.
+        let choice = Choice::from(u8::from(bit));
+        let scalars = || {
+            self.basis
+                .scalar_iter()
+                .map(|scalar| ct_select(<Q as Field>::ValueT::ZERO, scalar, choice))
+        };
+        for (row, scalar) in rgsw.minus_s_m_mut().iter_mut().zip(scalars()) {
+            row.a_mut_slice()
+                .iter_mut()
+                .for_each(|v| Q::add_assign(v, scalar));
+        }
+        for (row, scalar) in rgsw.m_mut().iter_mut().zip(scalars()) {
+            row.b_mut_slice()
+                .iter_mut()
+                .for_each(|v| Q::add_assign(v, scalar));
+        }
+
+        rgsw
+    }
+
+    /// Computes the external product `rgsw ⊡ cipher`.
+    ///
+    /// If `rgsw` encrypts a small message `m`, typically a bit or `±Xⁱ`,
+    /// and `cipher` encrypts `μ`, the result encrypts `m·μ`.
+    /// The noise grows by about `sqrt(2·l·N)·B/2·σ` for `l` decompose levels of basis `B`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `cipher` is not equal to the dimension.
+    pub fn external_product(
+        &self,
+        rgsw: &NttRgswCiphertext<Q>,
+        cipher: &RlweCiphertext<Q>,
+        ntt_table: &<Q as NttField>::Table,
+    ) -> RlweCiphertext<Q> {
+        assert_eq!(cipher.dimension(), self.dimension);
+
+        let mut decompose_space = PolyDecomposeSpace::new(self.dimension);
+        let mut median = NttRlweSpace::new(self.dimension);
+        let mut result = RlweCiphertext::zero(self.dimension);
+
+        cipher.mul_ntt_rgsw_inplace(
+            rgsw,
+            ntt_table,
+            &mut decompose_space,
+            &mut median,
+            &mut result,
+        );
+
+        result
+    }
+
+    /// Homomorphically selects `ct0` if `selector` encrypts `0`, or `ct1` if it encrypts `1`,
+    /// computed as `ct0 + selector ⊡ (ct1 - ct0)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `ct0` or `ct1` is not equal to the dimension.
+    pub fn cmux(
+        &self,
+        selector: &NttRgswCiphertext<Q>,
+        ct0: &RlweCiphertext<Q>,
+        ct1: &RlweCiphertext<Q>,
+        ntt_table: &<Q as NttField>::Table,
+    ) -> RlweCiphertext<Q> {
+        let diff = ct1.clone().sub_element_wise(ct0);
+        self.external_product(selector, &diff, ntt_table)
+            .add_element_wise(ct0)
+    }
+
+    /// Blind rotates `lut` by the phase of `cipher` with a sequence of [`Self::cmux`].
+    ///
+    /// `keys` are the RGSW encryptions of the binary LWE secret key bits `s_i`
+    /// and `cipher = (a, b)` has modulus `2N`. Starting from the trivial encryption of
+    /// `lut * X^(-b)`, each step selects between `ACC` and `ACC * X^(a_i)` by `s_i`,
+    /// so the result encrypts `lut * X^(-(b - <a, s>))`.
+    ///
+    /// This is the plain form of the loop, see
+    /// [`BlindRotationKey`](crate::BlindRotationKey) for the version reusing preallocated space.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coefficient count of `lut` is not equal to the dimension,
+    /// the LWE dimension of `cipher` is not equal to `keys.len()`,
+    /// or a value of `cipher` is not less than `2N`.
+    /// Modulus switch the ciphertext to `2N` first.
+    pub fn blind_rotate<C: UnsignedInteger>(
+        &self,
+        keys: &[NttRgswCiphertext<Q>],
+        lut: FieldPolynomial<Q>,
+        cipher: &LweCiphertext<C>,
+        ntt_table: &<Q as NttField>::Table,
+    ) -> RlweCiphertext<Q> {
+        let dimension = self.dimension;
+        let twice_dimension = dimension << 1;
+        assert_eq!(lut.coeff_count(), dimension);
+        assert_eq!(cipher.dimension(), keys.len());
+
+        let rotate = |acc: &RlweCiphertext<Q>, r: usize| {
+            let mut rotated = RlweCiphertext::zero(dimension);
+            rotated.add_assign_rhs_mul_monic_monomial(acc, dimension, r % twice_dimension);
+            rotated
+        };
+
+        let b: usize = cipher.b().as_into();
+        assert!(
+            b < twice_dimension,
+            "b = {b} is not less than 2N = {twice_dimension}"
+        );
+        let trivial = RlweCiphertext::new(FieldPolynomial::zero(dimension), lut);
+        let acc = rotate(&trivial, twice_dimension - b);
+
+        keys.iter().zip(cipher.a()).fold(acc, |acc, (key, &ai)| {
+            let ai: usize = ai.as_into();
+            assert!(
+                ai < twice_dimension,
+                "a_i = {ai} is not less than 2N = {twice_dimension}"
+            );
+            if ai == 0 {
+                acc
+            } else {
+                let rotated = rotate(&acc, ai);
+                self.cmux(key, &acc, &rotated, ntt_table)
+            }
+        })
+    }
+
+    /// Extracts the `index`-th coefficient of the plaintext of `cipher`
+    /// as a [`LweCiphertext`] with dimension **N** and modulus **Q**.
+    ///
+    /// The result is encrypted under the LWE secret key with the same coefficients
+    /// as the RLWE secret key, see [`LweSecretKey::from_rlwe_secret_key`](crate::LweSecretKey::from_rlwe_secret_key).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `cipher` is not equal to the dimension,
+    /// or `index` is not less than the dimension.
+    #[inline]
+    pub fn sample_extract(
+        &self,
+        cipher: &RlweCiphertext<Q>,
+        index: usize,
+    ) -> LweCiphertext<<Q as Field>::ValueT> {
+        assert_eq!(cipher.dimension(), self.dimension);
+        assert!(index < self.dimension);
+
+        cipher.extract_lwe_with_index(index)
+    }
+
+    /// Generates the [`AutoKey<Q>`] for the automorphism `X -> X^degree`,
+    /// with the basis and noise distribution of these parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degree` is even or not less than `2N`.
+    pub fn generate_auto_key<R>(
+        &self,
+        secret_key: &RlweSecretKey<Q>,
+        ntt_secret_key: &NttRlweSecretKey<Q>,
+        degree: usize,
+        ntt_table: Arc<<Q as NttField>::Table>,
+        rng: &mut R,
+    ) -> AutoKey<Q>
+    where
+        R: Rng + CryptoRng,
+    {
+        assert!(degree % 2 == 1 && degree < self.dimension << 1);
+
+        AutoKey::new(
+            secret_key,
+            ntt_secret_key,
+            degree,
+            &self.basis,
+            self.noise_distribution(),
+            ntt_table,
+            rng,
+        )
+    }
+
+    /// Applies the automorphism `X -> X^degree` to `cipher` with the key of that degree in `keys`.
+    ///
+    /// If `cipher` encrypts `m(X)`, the result encrypts `m(X^degree)` under the same secret key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `cipher` is not equal to the dimension,
+    /// or `keys` holds no key of `degree`.
+    pub fn apply_automorphism(
+        &self,
+        keys: &[AutoKey<Q>],
+        cipher: &RlweCiphertext<Q>,
+        degree: usize,
+    ) -> RlweCiphertext<Q> {
+        assert_eq!(cipher.dimension(), self.dimension);
+
+        let key = keys
+            .iter()
+            .find(|key| key.degree() == degree)
+            .expect("no automorphism key of the degree");
+
+        key.automorphism(cipher)
+    }
+
+    /// Generates the [`TraceKey<Q>`] with the basis and noise distribution of these parameters.
+    pub fn generate_trace_key<R>(
+        &self,
+        secret_key: &RlweSecretKey<Q>,
+        ntt_secret_key: &NttRlweSecretKey<Q>,
+        ntt_table: Arc<<Q as NttField>::Table>,
+        rng: &mut R,
+    ) -> TraceKey<Q>
+    where
+        R: Rng + CryptoRng,
+    {
+        TraceKey::new(
+            secret_key,
+            ntt_secret_key,
+            &self.basis,
+            self.noise_distribution(),
+            ntt_table,
+            rng,
+        )
+    }
+
+    /// Cyclically rotates the coefficient slots of `cipher`, as packed by
+    /// [`Self::encrypt_packed`], by `k` positions, so the `i`-th message moves to `(i + k) mod N`.
+    /// A negative `k` rotates the other way.
+    ///
+    /// Multiplying by `X^k` rotates negacyclically, negating the messages that wrap around.
+    /// Each of these coefficients is isolated with one trace, `Tr(X^(-i) * c / N)`,
+    /// and its sign is fixed. Since `X^(k+N) = -X^k` negates the other coefficients,
+    /// the shift negating fewer of them is used, so at most `N/2` traces are done.
+    /// The noise grows with the number of traces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension of `cipher` is not equal to the dimension.
+    pub fn rotate(
+        &self,
+        trace_key: &TraceKey<Q>,
+        cipher: &RlweCiphertext<Q>,
+        k: isize,
+    ) -> RlweCiphertext<Q> {
+        assert_eq!(cipher.dimension(), self.dimension);
+
+        let n = self.dimension;
+        let twice_n = n << 1;
+        let k = k.rem_euclid(n as isize) as usize;
+        if k == 0 {
+            return cipher.clone();
+        }
+
+        // The coefficients in `negated` hold the negated messages after multiplying by `X^shift`.
+        let (shift, negated) = if k <= n / 2 { (k, 0..k) } else { (k + n, k..n) };
+
+        let mut rotated = RlweCiphertext::zero(n);
+        rotated.add_assign_rhs_mul_monic_monomial(cipher, n, shift);
+
+        let n_inv = Q::inv(Q::from_canonical(<Q as Field>::ValueT::as_from(n)));
+        let mut correction = RlweCiphertext::zero(n);
+        for i in negated {
+            let mut shifted = RlweCiphertext::zero(n);
+            shifted.add_assign_rhs_mul_monic_monomial(&rotated, n, (twice_n - i) % twice_n);
+            let (a, b) = shifted.a_b_mut();
+            a.mul_scalar_assign(n_inv);
+            b.mul_scalar_assign(n_inv);
+
+            let coefficient = trace_key.trace(&shifted);
+            correction.add_assign_rhs_mul_monic_monomial(&coefficient, n, i);
+        }
+
+        let twice_correction = correction.clone().add_element_wise(&correction);
+        rotated.sub_element_wise(&twice_correction)
+    }
+
+    /// Decomposes `poly` into signed base-`B` digit polynomials according to the basis,
+    /// from the least significant level to the most significant one.
+    ///
+    /// Every digit lies in `[-B/2, B/2)`, with negative digits represented modulo `Q`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coefficient count of `poly` is not equal to the dimension.
+    pub fn decompose(&self, poly: &FieldPolynomial<Q>) -> Vec<FieldPolynomial<Q>> {
+        assert_eq!(poly.coeff_count(), self.dimension);
+
+        let mut carries = vec![false; self.dimension];
+        let mut adjust_poly = FieldPolynomial::zero(self.dimension);
+        poly.init_adjust_poly_carries(&self.basis, &mut carries, &mut adjust_poly);
+
+        self.basis
+            .decompose_iter()
+            .map(|once_decompose| {
+                let mut decomposed = FieldPolynomial::zero(self.dimension);
+                adjust_poly.approx_signed_decompose(
+                    once_decompose,
+                    &mut carries,
+                    decomposed.as_mut_slice(),
+                );
+                decomposed
+            })
+            .collect()
+    }
+
+    /// Recomposes the digit polynomials produced by [`Self::decompose`].
+    ///
+    /// The result equals the original polynomial when the basis drops no bits,
+    /// otherwise each coefficient differs by at most `2^(drop_bits - 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decomposed.len()` is not equal to the decompose length of the basis.
+    pub fn recompose(&self, decomposed: &[FieldPolynomial<Q>]) -> FieldPolynomial<Q> {
+        assert_eq!(decomposed.len(), self.basis.decompose_length());
+
+        let mut result = FieldPolynomial::zero(self.dimension);
+        for (digits, scalar) in decomposed.iter().zip(self.basis.scalar_iter()) {
+            result.add_mul_scalar_assign(digits, scalar);
+        }
+        result
+    }
 }
 
 impl<Q: NttField> Copy for GadgetRlweParameters<Q> {}
@@ -45,3 +664,31 @@ impl<Q: NttField> Clone for GadgetRlweParameters<Q> {
         *self
     }
 }
+
+impl GadgetRlweParameters<U32FieldEval<132120577>> {
+    /// The gadget RLWE parameters of a TFHE like scheme
+    /// with at least 128 bits by [`Self::estimated_security_bits`], about 136.
+    ///
+    /// They are the blind rotation part of `DEFAULT_128_BITS_PARAMETERS` in `boolean_fhe`.
+    ///
+    /// - dimension **N** = 1024, ternary secret key,
+    /// - modulus **Q** = 132120577, a 27-bit NTT friendly prime,
+    /// - noise standard deviation σ = 6.40,
+    /// - decompose basis `B = 2⁷`.
+    ///
+    /// The plaintext space is decided by the scheme on top of it,
+    /// the boolean scheme encodes `ℤ₄` messages with `Q/8` scaling.
+    #[inline]
+    pub fn preset_128bit_tfhe() -> Self {
+        const MODULUS: u32 = 132120577;
+
+        Self {
+            dimension: 1024,
+            modulus: MODULUS,
+            secret_key_type: RingSecretKeyType::Ternary,
+            noise_standard_deviation: 6.40,
+            basis: NonPowOf2ApproxSignedBasis::new(MODULUS, 7, None),
+            noise_distribution_type: NoiseDistributionType::Gaussian,
+        }
+    }
+}
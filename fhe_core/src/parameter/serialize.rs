@@ -0,0 +1,365 @@
+//! Versioned serialization of the parameters.
+//!
+//! Parameters are serialized with their defining values only, together with
+//! [`PARAMETERS_FORMAT_VERSION`]. Precomputed data, such as the barrett ratio of the
+//! modulus and the decompose basis, is rebuilt from these values when loading.
+
+use std::io::{Read, Write};
+
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis,
+    integer::UnsignedInteger,
+    reduce::{ModulusValue, RingReduce},
+    Field, NttField,
+};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    FHECoreError, GadgetRlweParameters, KeySwitchingParameters, LweParameters, LweSecretKeyType,
+    NoiseDistributionType, RingSecretKeyType,
+};
+
+/// The current format version of serialized parameters.
+pub const PARAMETERS_FORMAT_VERSION: u32 = 2;
+
+#[inline]
+fn check_version(version: u32) -> Result<(), FHECoreError> {
+    if version == PARAMETERS_FORMAT_VERSION {
+        Ok(())
+    } else {
+        Err(FHECoreError::ParametersVersionMismatch {
+            expected: PARAMETERS_FORMAT_VERSION,
+            found: version,
+        })
+    }
+}
+
+#[inline]
+fn check(condition: bool, message: &'static str) -> Result<(), FHECoreError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(FHECoreError::CorruptedParameters(message))
+    }
+}
+
+#[inline]
+fn save<T: Serialize, W: Write>(data: &T, writer: W) -> Result<(), FHECoreError> {
+    bincode::serialize_into(writer, data).map_err(FHECoreError::from)
+}
+
+#[inline]
+fn load<T: DeserializeOwned, R: Read>(reader: R) -> Result<T, FHECoreError> {
+    bincode::deserialize_from(reader).map_err(FHECoreError::from)
+}
+
+#[derive(Serialize, Deserialize)]
+struct LweParametersData<C> {
+    version: u32,
+    dimension: usize,
+    plain_modulus_value: C,
+    cipher_modulus_value: ModulusValue<C>,
+    secret_key_type: LweSecretKeyType,
+    noise_standard_deviation: f64,
+    noise_distribution_type: NoiseDistributionType,
+}
+
+impl<C: UnsignedInteger> LweParametersData<C> {
+    fn into_parameters<M: RingReduce<C>>(self) -> Result<LweParameters<C, M>, FHECoreError> {
+        check_version(self.version)?;
+        check(self.dimension > 0, "LWE dimension is zero")?;
+        check(
+            self.noise_standard_deviation.is_finite() && self.noise_standard_deviation >= 0.0,
+            "LWE noise standard deviation is not valid",
+        )?;
+        match self.cipher_modulus_value {
+            ModulusValue::Native => {}
+            ModulusValue::PowerOf2(q) => check(
+                q > C::ONE && q.is_power_of_two(),
+                "LWE cipher modulus is not a power of 2",
+            )?,
+            ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+                check(q > C::ONE, "LWE cipher modulus is too small")?
+            }
+        }
+        check(
+            self.plain_modulus_value > C::ONE
+                && self.plain_modulus_value <= self.cipher_modulus_value.modulus_minus_one(),
+            "LWE plain modulus is not valid",
+        )?;
+
+        let cipher_modulus = M::try_from_value(self.cipher_modulus_value).ok_or(
+            FHECoreError::CorruptedParameters("LWE cipher modulus does not fit the modulus type"),
+        )?;
+
+        let mut params = LweParameters::new_unchecked(
+            self.dimension,
+            self.plain_modulus_value,
+            cipher_modulus,
+            self.secret_key_type,
+            self.noise_standard_deviation,
+        );
+        params.noise_distribution_type = self.noise_distribution_type;
+        Ok(params)
+    }
+}
+
+impl<C: UnsignedInteger, M: RingReduce<C>> LweParameters<C, M> {
+    fn to_data(self) -> LweParametersData<C> {
+        LweParametersData {
+            version: PARAMETERS_FORMAT_VERSION,
+            dimension: self.dimension,
+            plain_modulus_value: self.plain_modulus_value,
+            cipher_modulus_value: self.cipher_modulus_value,
+            secret_key_type: self.secret_key_type,
+            noise_standard_deviation: self.noise_standard_deviation,
+            noise_distribution_type: self.noise_distribution_type,
+        }
+    }
+}
+
+impl<C, M> LweParameters<C, M>
+where
+    C: UnsignedInteger + Serialize + DeserializeOwned,
+    M: RingReduce<C>,
+{
+    /// Writes these parameters to `writer`, tagged with [`PARAMETERS_FORMAT_VERSION`].
+    #[inline]
+    pub fn save<W: Write>(&self, writer: W) -> Result<(), FHECoreError> {
+        save(&self.to_data(), writer)
+    }
+
+    /// Reads parameters written by [`LweParameters::save`] from `reader`.
+    ///
+    /// Returns an error if the data is truncated, corrupted or of another format version,
+    /// or if the stored modulus can't be represented by `M`,
+    /// e.g. a prime modulus for [`PowOf2Modulus`](algebra::modulus::PowOf2Modulus).
+    #[inline]
+    pub fn load<R: Read>(reader: R) -> Result<Self, FHECoreError> {
+        load::<LweParametersData<C>, R>(reader)?.into_parameters()
+    }
+}
+
+impl<C, M> Serialize for LweParameters<C, M>
+where
+    C: UnsignedInteger + Serialize,
+    M: RingReduce<C>,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_data().serialize(serializer)
+    }
+}
+
+impl<'de, C, M> Deserialize<'de> for LweParameters<C, M>
+where
+    C: UnsignedInteger + Deserialize<'de>,
+    M: RingReduce<C>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        LweParametersData::<C>::deserialize(deserializer)?
+            .into_parameters()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct GadgetRlweParametersData<T> {
+    version: u32,
+    dimension: usize,
+    modulus: T,
+    secret_key_type: RingSecretKeyType,
+    noise_standard_deviation: f64,
+    log_basis: u32,
+    decompose_length: usize,
+    noise_distribution_type: NoiseDistributionType,
+}
+
+impl<T: UnsignedInteger> GadgetRlweParametersData<T> {
+    fn into_parameters<Q>(self) -> Result<GadgetRlweParameters<Q>, FHECoreError>
+    where
+        Q: NttField<ValueT = T>,
+    {
+        check_version(self.version)?;
+        check(
+            self.dimension > 1 && self.dimension.is_power_of_two(),
+            "ring dimension is not a power of 2",
+        )?;
+        check(
+            self.modulus == Q::MODULUS_VALUE,
+            "ring modulus doesn't match the field",
+        )?;
+        check(
+            self.noise_standard_deviation.is_finite() && self.noise_standard_deviation >= 0.0,
+            "ring noise standard deviation is not valid",
+        )?;
+
+        let modulus_bits = T::BITS - self.modulus.leading_zeros();
+        check(
+            self.log_basis > 0 && self.log_basis <= modulus_bits,
+            "log basis is not valid",
+        )?;
+        check(
+            self.decompose_length > 0
+                && self.decompose_length <= (modulus_bits / self.log_basis) as usize,
+            "decompose length is not valid",
+        )?;
+
+        Ok(GadgetRlweParameters {
+            dimension: self.dimension,
+            modulus: self.modulus,
+            secret_key_type: self.secret_key_type,
+            noise_standard_deviation: self.noise_standard_deviation,
+            basis: NonPowOf2ApproxSignedBasis::new(
+                self.modulus,
+                self.log_basis,
+                Some(self.decompose_length),
+            ),
+            noise_distribution_type: self.noise_distribution_type,
+        })
+    }
+}
+
+impl<Q: NttField> GadgetRlweParameters<Q> {
+    fn to_data(&self) -> GadgetRlweParametersData<<Q as Field>::ValueT> {
+        GadgetRlweParametersData {
+            version: PARAMETERS_FORMAT_VERSION,
+            dimension: self.dimension,
+            modulus: self.modulus,
+            secret_key_type: self.secret_key_type,
+            noise_standard_deviation: self.noise_standard_deviation,
+            log_basis: self.basis.log_basis(),
+            decompose_length: self.basis.decompose_length(),
+            noise_distribution_type: self.noise_distribution_type,
+        }
+    }
+}
+
+impl<Q> GadgetRlweParameters<Q>
+where
+    Q: NttField,
+    <Q as Field>::ValueT: Serialize + DeserializeOwned,
+{
+    /// Writes these parameters to `writer`, tagged with [`PARAMETERS_FORMAT_VERSION`].
+    #[inline]
+    pub fn save<W: Write>(&self, writer: W) -> Result<(), FHECoreError> {
+        save(&self.to_data(), writer)
+    }
+
+    /// Reads parameters written by [`GadgetRlweParameters::save`] from `reader`,
+    /// the decompose basis is rebuilt from the stored values.
+    ///
+    /// Returns an error if the data is truncated, corrupted, of another format version
+    /// or the stored modulus is not the modulus of `Q`.
+    #[inline]
+    pub fn load<R: Read>(reader: R) -> Result<Self, FHECoreError> {
+        load::<GadgetRlweParametersData<<Q as Field>::ValueT>, R>(reader)?.into_parameters()
+    }
+}
+
+impl<Q> Serialize for GadgetRlweParameters<Q>
+where
+    Q: NttField,
+    <Q as Field>::ValueT: Serialize,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_data().serialize(serializer)
+    }
+}
+
+impl<'de, Q> Deserialize<'de> for GadgetRlweParameters<Q>
+where
+    Q: NttField,
+    <Q as Field>::ValueT: Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        GadgetRlweParametersData::<<Q as Field>::ValueT>::deserialize(deserializer)?
+            .into_parameters()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeySwitchingParametersData {
+    version: u32,
+    input_cipher_dimension: usize,
+    output_cipher_dimension: usize,
+    log_modulus: u32,
+    log_basis: u32,
+    reverse_length: Option<usize>,
+    noise_standard_deviation: f64,
+}
+
+impl KeySwitchingParametersData {
+    fn into_parameters(self) -> Result<KeySwitchingParameters, FHECoreError> {
+        check_version(self.version)?;
+        check(
+            self.input_cipher_dimension > 0 && self.output_cipher_dimension > 0,
+            "key switching dimension is zero",
+        )?;
+        check(
+            self.log_basis > 0 && self.log_basis <= self.log_modulus,
+            "key switching log basis is not valid",
+        )?;
+        check(
+            self.noise_standard_deviation.is_finite() && self.noise_standard_deviation >= 0.0,
+            "key switching noise standard deviation is not valid",
+        )?;
+
+        Ok(KeySwitchingParameters {
+            input_cipher_dimension: self.input_cipher_dimension,
+            output_cipher_dimension: self.output_cipher_dimension,
+            log_modulus: self.log_modulus,
+            log_basis: self.log_basis,
+            reverse_length: self.reverse_length,
+            noise_standard_deviation: self.noise_standard_deviation,
+        })
+    }
+}
+
+impl KeySwitchingParameters {
+    fn to_data(self) -> KeySwitchingParametersData {
+        KeySwitchingParametersData {
+            version: PARAMETERS_FORMAT_VERSION,
+            input_cipher_dimension: self.input_cipher_dimension,
+            output_cipher_dimension: self.output_cipher_dimension,
+            log_modulus: self.log_modulus,
+            log_basis: self.log_basis,
+            reverse_length: self.reverse_length,
+            noise_standard_deviation: self.noise_standard_deviation,
+        }
+    }
+
+    /// Writes these parameters to `writer`, tagged with [`PARAMETERS_FORMAT_VERSION`].
+    #[inline]
+    pub fn save<W: Write>(&self, writer: W) -> Result<(), FHECoreError> {
+        save(&self.to_data(), writer)
+    }
+
+    /// Reads parameters written by [`KeySwitchingParameters::save`] from `reader`.
+    ///
+    /// Returns an error if the data is truncated, corrupted or of another format version.
+    #[inline]
+    pub fn load<R: Read>(reader: R) -> Result<Self, FHECoreError> {
+        load::<KeySwitchingParametersData, R>(reader)?.into_parameters()
+    }
+}
+
+impl Serialize for KeySwitchingParameters {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_data().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KeySwitchingParameters {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        KeySwitchingParametersData::deserialize(deserializer)?
+            .into_parameters()
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -1,4 +1,20 @@
-use algebra::{integer::UnsignedInteger, reduce::ModulusValue};
+use algebra::{
+    integer::{ct_eq, ct_select, AsInto, UnsignedInteger},
+    reduce::ModulusValue,
+};
+
+use crate::FHECoreError;
+
+/// What [`encode_with_policy`] does with a message not less than the message space `t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Returns [`FHECoreError::MessageOutOfRange`].
+    Error,
+    /// Reduces the message modulo `t`.
+    Wrap,
+    /// Replaces the message with the largest message `t - 1`.
+    Saturate,
+}
 
 /// Encodes a message.
 ///
@@ -15,10 +31,49 @@ where
     match q {
         ModulusValue::Native => encode_native(message, t),
         ModulusValue::PowerOf2(q) => encode_pow_of_2(message, t, q),
-        ModulusValue::Prime(_) | ModulusValue::Others(_) => unimplemented!(),
+        ModulusValue::Prime(q) | ModulusValue::Others(q) => encode_normal(message, t, q),
     }
 }
 
+/// Encodes a message as [`encode`] does,
+/// handling a message not less than `t` according to `policy` instead of panicking.
+///
+/// # Parameters
+///
+/// - `t` is message space
+/// - `q` is LWE modulus value.
+///
+/// # Errors
+///
+/// Returns [`FHECoreError::MessageOutOfRange`] if `message` is not less than `t`
+/// and `policy` is [`OverflowPolicy::Error`].
+#[inline]
+pub fn encode_with_policy<C>(
+    message: C,
+    t: C,
+    q: ModulusValue<C>,
+    policy: OverflowPolicy,
+) -> Result<C, FHECoreError>
+where
+    C: UnsignedInteger,
+{
+    let message = if message < t {
+        message
+    } else {
+        match policy {
+            OverflowPolicy::Error => {
+                return Err(FHECoreError::MessageOutOfRange {
+                    message: Box::new(message),
+                    plain_modulus: Box::new(t),
+                })
+            }
+            OverflowPolicy::Wrap => message % t,
+            OverflowPolicy::Saturate => t - C::ONE,
+        }
+    };
+    Ok(encode(message, t, q))
+}
+
 /// Encodes a message.
 ///
 /// # Parameters
@@ -77,6 +132,36 @@ where
     message << (C::BITS - t.trailing_zeros())
 }
 
+/// Encodes a message as `round(message * q / t)`.
+///
+/// # Parameters
+///
+/// - `t` is message space
+/// - `q` is LWE modulus value.
+/// - This function needs `t * t` fits in `C`.
+///
+/// # Panic
+///
+/// Panics if the message exceeds the message space.
+#[inline]
+pub fn encode_normal<M, C>(message: M, t: C, q: C) -> C
+where
+    C: UnsignedInteger,
+    M: TryInto<C>,
+{
+    let message: C = message
+        .try_into()
+        .map_err(|_| "out of range integral type conversion attempted")
+        .unwrap();
+    assert!(
+        message < t,
+        "message {message} is bigger than the message space"
+    );
+    // round(m * q / t) = m * (q / t) + round(m * (q % t) / t)
+    let (q_div_t, q_rem_t) = (q / t, q % t);
+    message * q_div_t + (message * q_rem_t + (t >> 1u32)) / t
+}
+
 /// Decodes an encode value.
 ///
 /// # Parameters
@@ -92,7 +177,7 @@ where
     match q {
         ModulusValue::Native => decode_native(cipher, t),
         ModulusValue::PowerOf2(q) => decode_pow_of_2(cipher, t, q),
-        ModulusValue::Prime(_) | ModulusValue::Others(_) => unimplemented!(),
+        ModulusValue::Prime(q) | ModulusValue::Others(q) => decode_normal(cipher, t, q),
     }
 }
 
@@ -151,3 +236,114 @@ where
         .map_err(|_| "out of range integral type conversion attempted")
         .unwrap()
 }
+
+/// Decodes an encode value as `round(cipher * t / q) mod t`.
+///
+/// The message is counted as the number of rounding thresholds
+/// `ceil((2k - 1) * q / 2t)` that `cipher` reaches, so the running time
+/// only depends on `t`, not on `cipher`.
+///
+/// # Parameters
+///
+/// - `t` is message space
+/// - `q` is LWE modulus value.
+/// - This function needs `4 * t * t` fits in `C`.
+///
+/// # Panic
+///
+/// Panics if the decoded message cannot fit in `M`.
+#[inline]
+pub fn decode_normal<M, C>(cipher: C, t: C, q: C) -> M
+where
+    M: TryFrom<C>,
+    C: UnsignedInteger,
+{
+    let double_t = t << 1u32;
+    let (q_div, q_rem) = (q / double_t, q % double_t);
+
+    let mut decoded = C::ZERO;
+    let mut odd = C::ONE;
+    while odd < double_t {
+        // threshold = ceil(odd * q / 2t)
+        let threshold = odd * q_div + (odd * q_rem + double_t - C::ONE) / double_t;
+        decoded += C::as_from(cipher >= threshold);
+        odd += C::TWO;
+    }
+    let decoded = ct_select(decoded, C::ZERO, ct_eq(decoded, t));
+
+    M::try_from(decoded)
+        .map_err(|_| "out of range integral type conversion attempted")
+        .unwrap()
+}
+
+/// Encodes a real number as `round(value * scale) mod q`.
+///
+/// Rounding is to the nearest integer with ties away from zero, so that
+/// [`decode_f64`] of the result is within `1 / (2 * scale)` of `value`.
+/// Negative values are represented as `q - round(|value| * scale)`.
+///
+/// The encoding is exact while `|round(value * scale)|` is less than `2⁵³`,
+/// larger values lose the low bits to the precision of [`f64`].
+///
+/// # Parameters
+///
+/// - `scale` is the scaling factor, the precision is `1 / scale`.
+/// - `q` is LWE modulus value.
+///
+/// # Panic
+///
+/// Panics if `value * scale` is not finite, or `|round(value * scale)|`
+/// is not less than `q / 2`, since it would wrap around the modulus.
+#[inline]
+pub fn encode_f64<C>(value: f64, scale: f64, q: ModulusValue<C>) -> C
+where
+    C: UnsignedInteger,
+{
+    let scaled = (value * scale).round();
+    let half_q = modulus_f64(q) / 2.0;
+    assert!(
+        scaled.is_finite() && scaled.abs() < half_q,
+        "scaled value {scaled} overflows the modulus"
+    );
+
+    let magnitude = C::as_from(scaled.abs());
+    if scaled < 0.0 {
+        match q {
+            ModulusValue::Native => magnitude.wrapping_neg(),
+            ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+                q - magnitude
+            }
+        }
+    } else {
+        magnitude
+    }
+}
+
+/// Decodes a value encoded by [`encode_f64`] back to a real number.
+///
+/// `encoded` is lifted to the centered range `[-q/2, q/2)` and divided by `scale`,
+/// so an additional noise `e` in `encoded` adds `e / scale` to the result.
+///
+/// # Parameters
+///
+/// - `scale` is the scaling factor used to encode.
+/// - `q` is LWE modulus value.
+#[inline]
+pub fn decode_f64<C>(encoded: C, scale: f64, q: ModulusValue<C>) -> f64
+where
+    C: UnsignedInteger,
+{
+    let q = modulus_f64(q);
+    let value: f64 = encoded.as_into();
+    let centered = if value >= q / 2.0 { value - q } else { value };
+    centered / scale
+}
+
+/// Returns the modulus value as [`f64`].
+#[inline]
+fn modulus_f64<C: UnsignedInteger>(q: ModulusValue<C>) -> f64 {
+    match q {
+        ModulusValue::Native => 2.0f64.powi(C::BITS as i32),
+        ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) => q.as_into(),
+    }
+}
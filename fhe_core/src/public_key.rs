@@ -1,7 +1,7 @@
 use algebra::{
     integer::UnsignedInteger,
     polynomial::{FieldNttPolynomial, FieldPolynomial, Polynomial},
-    random::{sample_binary_values, DiscreteGaussian},
+    random::sample_binary_values,
     reduce::RingReduce,
     Field, NttField,
 };
@@ -23,14 +23,14 @@ pub struct LwePublicKey<C: UnsignedInteger> {
 
 impl<C: UnsignedInteger> LwePublicKey<C> {
     /// Creates a new `LwePublicKey` using the provided secret key,
-    /// parameters, modulus, Gaussian distribution, and random number generator.
+    /// parameters, modulus, noise distribution, and random number generator.
     ///
     /// # Arguments
     ///
     /// * `secret_key` - A reference to the [LweSecretKey] used to generate the public key.
     /// * `params` - The parameters for the LWE scheme.
     /// * `modulus` - The modulus used for the LWE scheme.
-    /// * `gaussian` - The Gaussian distribution used for generating random samples.
+    /// * `noise` - The noise distribution used for generating random samples.
     /// * `rng` - A mutable reference to a random number generator.
     ///
     /// # Returns
@@ -46,13 +46,13 @@ impl<C: UnsignedInteger> LwePublicKey<C> {
         R: Rng + CryptoRng,
         Modulus: RingReduce<C>,
     {
-        let gaussian = params.noise_distribution();
+        let noise = params.noise_distribution();
         let public_key: Vec<_> = (0..params.dimension)
             .map(|_| {
                 Lwe::generate_random_zero_sample(
                     secret_key.as_ref(),
                     params.cipher_modulus,
-                    gaussian,
+                    noise,
                     rng,
                 )
             })
@@ -86,7 +86,7 @@ impl<C: UnsignedInteger> LwePublicKey<C> {
         Modulus: RingReduce<C>,
     {
         let dimension = params.dimension;
-        let gaussian = params.noise_distribution();
+        let noise = params.noise_distribution();
         let modulus = params.cipher_modulus;
 
         let r: Vec<C> = sample_binary_values(dimension, rng);
@@ -111,14 +111,10 @@ impl<C: UnsignedInteger> LwePublicKey<C> {
             result.add_reduce_assign_component_wise(zero, modulus);
         }
 
-        for (ai, ei) in result
-            .a_mut()
-            .iter_mut()
-            .zip(gaussian.sample_iter(&mut *rng))
-        {
+        for (ai, ei) in result.a_mut().iter_mut().zip(noise.sample_iter(&mut *rng)) {
             modulus.reduce_add_assign(ai, ei);
         }
-        modulus.reduce_add_assign(result.b_mut(), gaussian.sample(rng));
+        modulus.reduce_add_assign(result.b_mut(), noise.sample(rng));
 
         result
     }
@@ -158,11 +154,11 @@ impl<C: UnsignedInteger> LwePublicKeyRlweMode<C> {
         LweModulus: RingReduce<C>,
     {
         let dimension = params.dimension;
-        let gaussian = params.noise_distribution();
+        let noise = params.noise_distribution();
         let modulus = params.cipher_modulus;
 
         let a = Polynomial::random(modulus.modulus_minus_one(), dimension, rng);
-        let mut e = Polynomial::random_gaussian(gaussian, dimension, rng);
+        let mut e = Polynomial::random_noise(noise, dimension, rng);
 
         a.naive_mul_inplace(secret_key, modulus, &mut e);
 
@@ -196,7 +192,7 @@ impl<C: UnsignedInteger> LwePublicKeyRlweMode<C> {
         Modulus: RingReduce<C>,
     {
         let dimension = params.dimension;
-        let gaussian = params.noise_distribution();
+        let noise = params.noise_distribution();
         let modulus = params.cipher_modulus;
 
         let r: Vec<C> = sample_binary_values(dimension, csrng);
@@ -222,7 +218,7 @@ impl<C: UnsignedInteger> LwePublicKeyRlweMode<C> {
         for (ai, ei) in result
             .a_mut()
             .iter_mut()
-            .zip(gaussian.sample_iter(&mut *csrng))
+            .zip(noise.sample_iter(&mut *csrng))
         {
             modulus.reduce_add_assign(ai, ei);
         }
@@ -230,7 +226,7 @@ impl<C: UnsignedInteger> LwePublicKeyRlweMode<C> {
         for (bi, ei) in result
             .b_mut()
             .iter_mut()
-            .zip(gaussian.sample_iter(&mut *csrng))
+            .zip(noise.sample_iter(&mut *csrng))
         {
             modulus.reduce_add_assign(bi, ei);
         }
@@ -263,7 +259,7 @@ impl<C: UnsignedInteger> LwePublicKeyRlweMode<C> {
         Modulus: RingReduce<C>,
     {
         let dimension = params.dimension;
-        let gaussian = params.noise_distribution();
+        let noise = params.noise_distribution();
         let modulus = params.cipher_modulus;
 
         let r: Vec<C> = sample_binary_values(dimension, csrng);
@@ -291,7 +287,7 @@ impl<C: UnsignedInteger> LwePublicKeyRlweMode<C> {
         for (ai, ei) in result
             .a_mut()
             .iter_mut()
-            .zip(gaussian.sample_iter(&mut *csrng))
+            .zip(noise.sample_iter(&mut *csrng))
         {
             modulus.reduce_add_assign(ai, ei);
         }
@@ -299,7 +295,7 @@ impl<C: UnsignedInteger> LwePublicKeyRlweMode<C> {
         for (bi, ei) in result
             .b_mut()
             .iter_mut()
-            .zip(gaussian.sample_iter(&mut *csrng))
+            .zip(noise.sample_iter(&mut *csrng))
         {
             modulus.reduce_add_assign(bi, ei);
         }
@@ -318,32 +314,32 @@ pub struct NttRlwePublicKey<F: NttField> {
 }
 
 impl<F: NttField> NttRlwePublicKey<F> {
-    /// Creates a new `NttRlwePublicKey` using the provided secret key, Gaussian distribution, NTT table, and random number generator.
+    /// Creates a new `NttRlwePublicKey` using the provided secret key, noise distribution, NTT table, and random number generator.
     ///
     /// # Arguments
     ///
     /// * `secret_key` - A reference to the NttRlweSecretKey used to generate the public key.
-    /// * `gaussian` - The Gaussian distribution used for generating random samples.
+    /// * `noise` - The noise distribution used for generating random samples.
     /// * `ntt_table` - The NTT table used for Number Theoretic Transform operations.
     /// * `rng` - A mutable reference to a random number generator.
     ///
     /// # Returns
     ///
     /// A new instance of `NttRlwePublicKey`.
-    pub fn new<R>(
+    pub fn new<R, D>(
         secret_key: &NttRlweSecretKey<F>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> NttRlwePublicKey<F>
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let dimension = secret_key.coeff_count();
 
         let a = FieldNttPolynomial::random(dimension, rng);
-        let mut b =
-            FieldPolynomial::random_gaussian(dimension, gaussian, rng).into_ntt_poly(ntt_table);
+        let mut b = FieldPolynomial::random_noise(dimension, noise, rng).into_ntt_poly(ntt_table);
 
         b.add_mul_assign(&a, secret_key);
 
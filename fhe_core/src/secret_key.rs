@@ -1,20 +1,26 @@
 use std::ops::Deref;
 
 use algebra::{
-    integer::UnsignedInteger,
+    integer::{ct_eq, ct_select, UnsignedInteger},
     ntt::NumberTheoryTransform,
     polynomial::{FieldNttPolynomial, FieldPolynomial},
-    random::{sample_binary_values, sample_ternary_values, DiscreteGaussian},
+    random::{
+        sample_binary_values, sample_ternary_values, BinarySampler, DiscreteGaussian,
+        NoiseDistribution, TernarySampler,
+    },
     reduce::RingReduce,
     Field, NttField,
 };
-use num_traits::{ConstOne, ConstZero, One, Zero};
-use rand::{CryptoRng, Rng};
+use num_traits::ConstZero;
+use rand::{distributions::Distribution, CryptoRng, Rng};
 
-use crate::{decode, encode, LweCiphertext, LweParameters};
+use crate::{
+    constant_time::lwe_phase, decode, decode_f64, encode, encode_f64, LweCiphertext, LweParameters,
+};
 
 /// The distribution type of the LWE Secret Key.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LweSecretKeyType {
     /// Binary SecretKey Distribution.
     Binary,
@@ -25,6 +31,7 @@ pub enum LweSecretKeyType {
 
 /// The distribution type of the Ring Secret Key.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RingSecretKeyType {
     /// Binary SecretKey Distribution.
     Binary,
@@ -35,6 +42,79 @@ pub enum RingSecretKeyType {
     Gaussian,
 }
 
+/// The sampler of LWE secret key coefficients.
+///
+/// Negative coefficients are represented as `q - 1`.
+#[derive(Debug, Clone, Copy)]
+pub enum LweSecretKeyDistribution<C: UnsignedInteger> {
+    /// Uniform on `{0, 1}`.
+    Binary(BinarySampler),
+    /// `0` with probability `1/2`, `1` and `-1` with probability `1/4` each.
+    Ternary(TernarySampler<C>),
+}
+
+impl<C: UnsignedInteger> LweSecretKeyDistribution<C> {
+    /// Creates the sampler of `secret_key_type` for a cipher modulus `q`,
+    /// given `q - 1`.
+    #[inline]
+    pub fn new(secret_key_type: LweSecretKeyType, cipher_modulus_minus_one: C) -> Self {
+        match secret_key_type {
+            LweSecretKeyType::Binary => Self::Binary(BinarySampler),
+            LweSecretKeyType::Ternary => {
+                Self::Ternary(TernarySampler::new(cipher_modulus_minus_one))
+            }
+        }
+    }
+
+    /// Returns the [`LweSecretKeyType`] of this sampler.
+    #[inline]
+    pub fn secret_key_type(&self) -> LweSecretKeyType {
+        match self {
+            Self::Binary(_) => LweSecretKeyType::Binary,
+            Self::Ternary(_) => LweSecretKeyType::Ternary,
+        }
+    }
+}
+
+impl<C: UnsignedInteger> Distribution<C> for LweSecretKeyDistribution<C> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> C {
+        match self {
+            Self::Binary(s) => s.sample(rng),
+            Self::Ternary(s) => s.sample(rng),
+        }
+    }
+}
+
+impl<C: UnsignedInteger> NoiseDistribution<C> for LweSecretKeyDistribution<C> {
+    #[inline]
+    fn mean(&self) -> f64 {
+        match self {
+            Self::Binary(s) => NoiseDistribution::<C>::mean(s),
+            Self::Ternary(s) => s.mean(),
+        }
+    }
+
+    #[inline]
+    fn variance(&self) -> f64 {
+        match self {
+            Self::Binary(s) => NoiseDistribution::<C>::variance(s),
+            Self::Ternary(s) => s.variance(),
+        }
+    }
+
+    #[inline]
+    fn sample_values<R>(&self, length: usize, rng: &mut R) -> Vec<C>
+    where
+        R: Rng + CryptoRng,
+    {
+        match self {
+            Self::Binary(_) => sample_binary_values(length, rng),
+            Self::Ternary(s) => sample_ternary_values(s.minus_one(), length, rng),
+        }
+    }
+}
+
 /// Represents a secret key for the Learning with Errors (LWE) cryptographic scheme.
 ///
 /// # Type Parameters
@@ -56,6 +136,10 @@ impl<C: UnsignedInteger> AsRef<[C]> for LweSecretKey<C> {
 impl<C: UnsignedInteger> LweSecretKey<C> {
     /// Creates a new `LweSecretKey` with the specified key and distribution type.
     ///
+    /// The coefficients of a binary key must be `0` or `1`, those of a ternary key
+    /// `0`, `1` or `q - 1` for the cipher modulus `q` the key is used with.
+    /// The constant time [`Self::decrypt`] relies on it.
+    ///
     /// # Arguments
     ///
     /// * `key` - A vector containing the secret key coefficients.
@@ -64,8 +148,25 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
     /// # Returns
     ///
     /// A new instance of `LweSecretKey`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a binary key has a coefficient other than `0` or `1`,
+    /// or a ternary key has two different coefficients other than `0` and `1`.
     #[inline]
     pub fn new(key: Vec<C>, distr: LweSecretKeyType) -> Self {
+        let minus_one = key.iter().copied().find(|&s| s > C::ONE);
+        let valid = match (distr, minus_one) {
+            (_, None) => true,
+            (LweSecretKeyType::Binary, Some(_)) => false,
+            (LweSecretKeyType::Ternary, Some(minus_one)) => {
+                key.iter().all(|&s| s <= C::ONE || s == minus_one)
+            }
+        };
+        assert!(
+            valid,
+            "{distr:?} secret key coefficients must be 0, 1 or q - 1"
+        );
         Self { key, distr }
     }
 
@@ -95,14 +196,12 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
         R: Rng + CryptoRng,
         M: RingReduce<C>,
     {
-        let distr = params.secret_key_type;
-        let key = match distr {
-            LweSecretKeyType::Binary => sample_binary_values(params.dimension, rng),
-            LweSecretKeyType::Ternary => {
-                sample_ternary_values(params.cipher_modulus_minus_one, params.dimension, rng)
-            }
-        };
-        Self { key, distr }
+        let sampler = params.secret_key_distribution();
+        let key = sampler.sample_values(params.dimension, rng);
+        Self {
+            key,
+            distr: sampler.secret_key_type(),
+        }
     }
 
     /// Creates a new `LweSecretKey` from an RLWE secret key.
@@ -125,14 +224,11 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
             RingSecretKeyType::Ternary => LweSecretKeyType::Ternary,
             RingSecretKeyType::Gaussian => panic!("Not support"),
         };
-        let convert = |value: &<F as Field>::ValueT| {
-            if value.is_zero() {
-                C::ZERO
-            } else if value.is_one() {
-                C::ONE
-            } else {
-                lwe_cipher_modulus_minus_one
-            }
+        let convert = |&value: &<F as Field>::ValueT| {
+            let is_zero = ct_eq(value, <F as Field>::ValueT::ZERO);
+            let is_one = ct_eq(value, <F as Field>::ONE);
+            let value = ct_select(lwe_cipher_modulus_minus_one, C::ONE, is_one);
+            ct_select(value, C::ZERO, is_zero)
         };
 
         Self {
@@ -160,11 +256,11 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
         R: Rng + CryptoRng,
         Modulus: RingReduce<C>,
     {
-        let gaussian = params.noise_distribution();
+        let noise = params.noise_distribution();
         let modulus = params.cipher_modulus;
 
         let mut ciphertext =
-            LweCiphertext::generate_random_zero_sample(self.as_ref(), modulus, gaussian, rng);
+            LweCiphertext::generate_random_zero_sample(self.as_ref(), modulus, noise, rng);
         modulus.reduce_add_assign(
             ciphertext.b_mut(),
             encode(
@@ -177,13 +273,106 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
         ciphertext
     }
 
-    /// Decrypts the [`LweCiphertext`] back to message.
+    /// Encrypts a real `value` encoded by [`encode_f64`] with `scale`
+    /// into [`LweCiphertext`].
+    ///
+    /// The plain modulus of `params` is not used,
+    /// the precision is decided by `scale` and the noise.
+    ///
+    /// # Panic
+    ///
+    /// Panics if `|round(value * scale)|` is not less than `q / 2`.
+    #[inline]
+    pub fn encrypt_f64<R, Modulus>(
+        &self,
+        value: f64,
+        scale: f64,
+        params: &LweParameters<C, Modulus>,
+        rng: &mut R,
+    ) -> LweCiphertext<C>
+    where
+        R: Rng + CryptoRng,
+        Modulus: RingReduce<C>,
+    {
+        let noise = params.noise_distribution();
+        let modulus = params.cipher_modulus;
+
+        let mut ciphertext =
+            LweCiphertext::generate_random_zero_sample(self.as_ref(), modulus, noise, rng);
+        modulus.reduce_add_assign(
+            ciphertext.b_mut(),
+            encode_f64(value, scale, params.cipher_modulus_value),
+        );
+
+        ciphertext
+    }
+
+    /// Decrypts the [`LweCiphertext`] back to a real value with [`decode_f64`].
+    ///
+    /// The noise `e` is kept, so the result is within `(|e| + 1/2) / scale`
+    /// of the encrypted value.
+    #[inline]
+    pub fn decrypt_f64<Modulus>(
+        &self,
+        cipher_text: &LweCiphertext<C>,
+        scale: f64,
+        params: &LweParameters<C, Modulus>,
+    ) -> f64
+    where
+        Modulus: RingReduce<C>,
+    {
+        let plaintext = lwe_phase(
+            cipher_text.a(),
+            cipher_text.b(),
+            &self.key,
+            params.cipher_modulus_value,
+        );
+
+        decode_f64(plaintext, scale, params.cipher_modulus_value)
+    }
+
+    /// Decrypts the [`LweCiphertext`] back to message in constant time.
+    ///
+    /// Neither computing `b - a * s` nor decoding branches on or indexes memory by
+    /// the secret key or the plaintext, see [`Self::decrypt_vartime`] for a faster
+    /// variable time version.
+    ///
+    /// The key coefficients must be `0`, `1` or `q - 1`, as [`Self::new`] requires.
     #[inline]
     pub fn decrypt<Msg, Modulus>(
         &self,
         cipher_text: &LweCiphertext<C>,
         params: &LweParameters<C, Modulus>,
     ) -> Msg
+    where
+        Msg: TryFrom<C>,
+        Modulus: RingReduce<C>,
+    {
+        let plaintext = lwe_phase(
+            cipher_text.a(),
+            cipher_text.b(),
+            &self.key,
+            params.cipher_modulus_value,
+        );
+
+        decode(
+            plaintext,
+            params.plain_modulus_value,
+            params.cipher_modulus_value,
+        )
+    }
+
+    /// Decrypts the [`LweCiphertext`] back to message.
+    ///
+    /// This version uses the modulus operations of the parameters directly,
+    /// which may branch on secret dependent values.
+    /// Only use it when timing side channels are not a concern.
+    #[inline]
+    pub fn decrypt_vartime<Msg, Modulus>(
+        &self,
+        cipher_text: &LweCiphertext<C>,
+        params: &LweParameters<C, Modulus>,
+    ) -> Msg
     where
         Msg: TryFrom<C>,
         Modulus: RingReduce<C>,
@@ -307,14 +496,13 @@ impl<F: NttField> RlweSecretKey<F> {
     /// A new instance of `RlweSecretKey` created from the LWE secret key.
     #[inline]
     pub fn from_lwe_secret_key<C: UnsignedInteger>(lwe_secret_key: &LweSecretKey<C>) -> Self {
-        let convert = |v: &C| {
-            if v.is_zero() {
-                <<F as Field>::ValueT as ConstZero>::ZERO
-            } else if v.is_one() {
-                <<F as Field>::ValueT as ConstOne>::ONE
-            } else {
-                <F as Field>::MINUS_ONE
-            }
+        let convert = |&v: &C| {
+            let value = ct_select(<F as Field>::MINUS_ONE, <F as Field>::ONE, ct_eq(v, C::ONE));
+            ct_select(
+                value,
+                <<F as Field>::ValueT as ConstZero>::ZERO,
+                ct_eq(v, C::ZERO),
+            )
         };
         let distr = match lwe_secret_key.distr {
             LweSecretKeyType::Binary => RingSecretKeyType::Binary,
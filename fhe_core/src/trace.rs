@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
-use algebra::{decompose::NonPowOf2ApproxSignedBasis, random::DiscreteGaussian, Field, NttField};
+use algebra::{decompose::NonPowOf2ApproxSignedBasis, Field, NttField};
 use lattice::utils::RlweSpace;
-use rand::{CryptoRng, Rng};
+use rand::{distributions::Distribution, CryptoRng, Rng};
 
 use crate::{utils::Pool, AutoKey, AutoSpace, NttRlweSecretKey, RlweCiphertext, RlweSecretKey};
 
@@ -14,16 +14,17 @@ pub struct TraceKey<F: NttField> {
 
 impl<F: NttField> TraceKey<F> {
     /// Creates a new [`TraceKey<F>`].
-    pub fn new<R>(
+    pub fn new<R, D>(
         secret_key: &RlweSecretKey<F>,
         ntt_secret_key: &NttRlweSecretKey<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: Arc<<F as NttField>::Table>,
         rng: &mut R,
     ) -> TraceKey<F>
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let log_n = secret_key.coeff_count().trailing_zeros();
         let auto_keys: Vec<AutoKey<F>> = (1..=log_n)
@@ -35,7 +36,7 @@ impl<F: NttField> TraceKey<F> {
                     ntt_secret_key,
                     degree,
                     basis,
-                    gaussian,
+                    noise,
                     Arc::clone(&ntt_table),
                     rng,
                 )
@@ -96,7 +97,10 @@ impl<F: NttField> TraceKey<F> {
 
 #[cfg(test)]
 mod tests {
-    use algebra::{ntt::NumberTheoryTransform, polynomial::FieldPolynomial, Field, U32FieldEval};
+    use algebra::{
+        ntt::NumberTheoryTransform, polynomial::FieldPolynomial, random::DiscreteGaussian, Field,
+        U32FieldEval,
+    };
     use rand::{distributions::Uniform, prelude::Distribution};
 
     use crate::RingSecretKeyType;
@@ -129,7 +133,7 @@ mod tests {
 
         let mut csrng = rand::thread_rng();
 
-        let gaussian = DiscreteGaussian::new(0.0, 3.2, FieldT::MINUS_ONE).unwrap();
+        let noise = DiscreteGaussian::new(0.0, 3.2, FieldT::MINUS_ONE).unwrap();
         let distr = Uniform::new(0, PLAIN_MODULUS);
 
         let sk = RlweSecretKey::new(
@@ -144,7 +148,7 @@ mod tests {
             &sk,
             &ntt_sk,
             &basis,
-            gaussian,
+            noise,
             Arc::clone(&ntt_table),
             &mut csrng,
         );
@@ -153,7 +157,7 @@ mod tests {
         let encoded_values = PolyT::new(values.iter().copied().map(encode).collect());
 
         let mut cipher = <RlweCiphertext<FieldT>>::generate_random_zero_sample(
-            &ntt_sk, gaussian, &ntt_table, &mut csrng,
+            &ntt_sk, noise, &ntt_table, &mut csrng,
         );
         *cipher.b_mut() += &encoded_values;
 
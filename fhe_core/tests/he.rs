@@ -1,7 +1,22 @@
-use algebra::{modulus::PowOf2Modulus, reduce::ModulusValue};
-use fhe_core::{LweParameters, LwePublicKey, LwePublicKeyRlweMode, LweSecretKey, LweSecretKeyType};
+use std::sync::Arc;
+
+use algebra::ntt::NumberTheoryTransform;
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis,
+    modulus::{GoldilocksModulus, NativeModulus, PowOf2Modulus},
+    polynomial::FieldPolynomial,
+    random::Prg,
+    reduce::{ModulusValue, ReduceAddAssign, ReduceDotProduct, ReduceSub},
+    Field, NttField, U32FieldEval,
+};
+use fhe_core::{
+    decode, decode_f64, encode, encode_f64, encode_with_policy, FHECoreError, GadgetRlweParameters,
+    KeySwitchingParameters, LweParameters, LwePublicKey, LwePublicKeyRlweMode, LweSecretKey,
+    LweSecretKeyType, NoiseBudgetPlan, NoiseDistributionType, NoiseOperation, NttRlweSecretKey,
+    OverflowPolicy, ParameterError, RingSecretKeyType, RlweCiphertext, RlweSecretKey,
+};
 use lattice::Lwe;
-use rand::{distributions::Uniform, thread_rng, Rng};
+use rand::{distributions::Uniform, thread_rng, CryptoRng, Rng, SeedableRng};
 
 #[test]
 fn test_lwe_pk() {
@@ -26,6 +41,7 @@ fn test_lwe_pk() {
         cipher_modulus: modulus,
         secret_key_type: LweSecretKeyType::Binary,
         noise_standard_deviation: 3.20,
+        noise_distribution_type: NoiseDistributionType::Gaussian,
     };
 
     // generate secret key
@@ -63,3 +79,1661 @@ fn test_lwe_pk() {
     let m: MsgT = sk.decrypt(&c1, &params);
     assert_eq!(m, messages[index]);
 }
+
+#[test]
+fn test_lwe_params_encrypt_decrypt() {
+    type MsgT = u8;
+    type CipherT = u16;
+    type Modulus = PowOf2Modulus<CipherT>;
+
+    let mut rng = thread_rng();
+
+    let plain_modulus: CipherT = 8;
+    let cipher_modulus: CipherT = 2048;
+
+    let distr = Uniform::new(0, plain_modulus as MsgT);
+
+    let params = LweParameters::new_unchecked(
+        512,
+        plain_modulus,
+        Modulus::new(cipher_modulus),
+        LweSecretKeyType::Ternary,
+        3.20,
+    );
+
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    // encryption of zero decrypts to zero
+    let c = params.encrypt(&sk, 0 as MsgT, &mut rng);
+    let m: MsgT = params.decrypt(&sk, &c);
+    assert_eq!(m, 0);
+
+    for message in (&mut rng).sample_iter(distr).take(1000) {
+        let c = params.encrypt(&sk, message, &mut rng);
+        let m: MsgT = params.decrypt(&sk, &c);
+        assert_eq!(m, message);
+    }
+
+    // shifting `b` by a whole plaintext step moves the message by one
+    let message: MsgT = rng.sample(distr);
+    let mut c = params.encrypt(&sk, message, &mut rng);
+    params
+        .cipher_modulus
+        .reduce_add_assign(c.b_mut(), cipher_modulus / plain_modulus);
+    let m: MsgT = params.decrypt(&sk, &c);
+    assert_ne!(m, message);
+    assert_eq!(m, (message + 1) % plain_modulus as MsgT);
+}
+
+#[test]
+fn test_gadget_rlwe_params_encrypt_decrypt() {
+    type FieldT = U32FieldEval<132120577>;
+    type ValT = u32;
+    type PolyT = FieldPolynomial<FieldT>;
+
+    const CIPHER_MODULUS: ValT = FieldT::MODULUS_VALUE;
+    const PLAIN_MODULUS: ValT = 8;
+    const LOG_N: u32 = 10;
+    const N: usize = 1 << LOG_N;
+
+    let encode = |m: ValT| -> ValT {
+        (m as f64 * CIPHER_MODULUS as f64 / PLAIN_MODULUS as f64).round() as ValT
+    };
+    let decode = |c: ValT| -> ValT {
+        (c as f64 * PLAIN_MODULUS as f64 / CIPHER_MODULUS as f64).round() as ValT % PLAIN_MODULUS
+    };
+
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, PLAIN_MODULUS);
+
+    let params = GadgetRlweParameters::<FieldT> {
+        dimension: N,
+        modulus: CIPHER_MODULUS,
+        secret_key_type: RingSecretKeyType::Ternary,
+        noise_standard_deviation: 3.20,
+        basis: NonPowOf2ApproxSignedBasis::new(CIPHER_MODULUS, 4, None),
+        noise_distribution_type: NoiseDistributionType::Gaussian,
+    };
+    let ntt_table = FieldT::generate_ntt_table(LOG_N).unwrap();
+
+    let sk = params.generate_secret_key(&mut rng);
+    let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+
+    let m1: Vec<ValT> = (&mut rng).sample_iter(distr).take(N).collect();
+    let m2: Vec<ValT> = (&mut rng).sample_iter(distr).take(N).collect();
+
+    let c1 = params.encrypt_poly(
+        &ntt_sk,
+        &PolyT::new(m1.iter().copied().map(encode).collect()),
+        &ntt_table,
+        &mut rng,
+    );
+    let c2 = params.encrypt_poly(
+        &ntt_sk,
+        &PolyT::new(m2.iter().copied().map(encode).collect()),
+        &ntt_table,
+        &mut rng,
+    );
+
+    let decrypted: Vec<ValT> = params
+        .decrypt_poly(&ntt_sk, &c1, &ntt_table)
+        .into_iter()
+        .map(decode)
+        .collect();
+    assert_eq!(decrypted, m1);
+
+    let sum = c1.add_element_wise(&c2);
+    let decrypted: Vec<ValT> = params
+        .decrypt_poly(&ntt_sk, &sum, &ntt_table)
+        .into_iter()
+        .map(decode)
+        .collect();
+    let expected: Vec<ValT> = m1
+        .iter()
+        .zip(m2.iter())
+        .map(|(a, b)| (a + b) % PLAIN_MODULUS)
+        .collect();
+    assert_eq!(decrypted, expected);
+}
+
+#[test]
+fn test_gadget_rlwe_params_decompose_recompose() {
+    type FieldT = U32FieldEval<132120577>;
+    type ValT = u32;
+    type PolyT = FieldPolynomial<FieldT>;
+
+    const Q: ValT = FieldT::MODULUS_VALUE;
+    const N: usize = 1024;
+
+    let mut rng = thread_rng();
+
+    // `Q` has 27 bits, so a log basis of 3 drops no bits and 4 drops 3 bits.
+    for log_basis in [3, 4] {
+        let params = GadgetRlweParameters::<FieldT> {
+            dimension: N,
+            modulus: Q,
+            secret_key_type: RingSecretKeyType::Ternary,
+            noise_standard_deviation: 3.20,
+            basis: NonPowOf2ApproxSignedBasis::new(Q, log_basis, None),
+            noise_distribution_type: NoiseDistributionType::Gaussian,
+        };
+        let basis = params.basis();
+        let half_basis = basis.basis_value() >> 1;
+        let differ_max = basis.init_carry_mask().unwrap_or(0);
+
+        for _ in 0..16 {
+            let poly = PolyT::random(N, &mut rng);
+            let decomposed = params.decompose(&poly);
+            assert_eq!(decomposed.len(), basis.decompose_length());
+            assert!(decomposed
+                .iter()
+                .flat_map(|d| d.iter())
+                .all(|&d| d < half_basis || d >= Q - half_basis));
+
+            let recomposed = params.recompose(&decomposed);
+            if basis.drop_bits() == 0 {
+                assert_eq!(recomposed, poly);
+            } else {
+                for (&x, &y) in poly.iter().zip(recomposed.iter()) {
+                    let difference = FieldT::sub(x, y).min(FieldT::sub(y, x));
+                    assert!(difference <= differ_max);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lwe_key_switch_with_params() {
+    type MsgT = u8;
+    type CipherT = u16;
+    type Modulus = PowOf2Modulus<CipherT>;
+
+    let mut rng = thread_rng();
+
+    let plain_modulus: CipherT = 4;
+    let log_modulus = 14;
+    let cipher_modulus: CipherT = 1 << log_modulus;
+    let modulus = Modulus::new(cipher_modulus);
+
+    let distr = Uniform::new(0, plain_modulus as MsgT);
+
+    let params_in =
+        LweParameters::new_unchecked(512, plain_modulus, modulus, LweSecretKeyType::Binary, 3.20);
+    let params_out =
+        LweParameters::new_unchecked(256, plain_modulus, modulus, LweSecretKeyType::Binary, 3.20);
+
+    let ks_params = KeySwitchingParameters {
+        input_cipher_dimension: params_in.dimension,
+        output_cipher_dimension: params_out.dimension,
+        log_modulus,
+        log_basis: 2,
+        reverse_length: None,
+        noise_standard_deviation: 3.20,
+    };
+
+    let sk_a = LweSecretKey::generate(&params_in, &mut rng);
+    let sk_b = LweSecretKey::generate(&params_out, &mut rng);
+
+    let ksk = ks_params.generate_key(&sk_a, &sk_b, modulus, &mut rng);
+
+    let noise_bound = cipher_modulus / (plain_modulus * 2);
+    let mut max_fresh_noise = 0;
+    let mut max_switched_noise = 0;
+
+    for message in (&mut rng).sample_iter(distr).take(100) {
+        let c = params_in.encrypt(&sk_a, message, &mut rng);
+        let (m, fresh_noise): (MsgT, CipherT) = sk_a.decrypt_with_noise(&c, &params_in);
+        assert_eq!(m, message);
+
+        let switched = ks_params.key_switch(&ksk, &c, modulus);
+        assert_eq!(switched.a().len(), params_out.dimension);
+
+        let (m, switched_noise): (MsgT, CipherT) = sk_b.decrypt_with_noise(&switched, &params_out);
+        assert_eq!(m, message);
+        assert!(switched_noise < noise_bound);
+
+        max_fresh_noise = max_fresh_noise.max(fresh_noise);
+        max_switched_noise = max_switched_noise.max(switched_noise);
+    }
+
+    // key switching accumulates the noise of every key switching key sample it uses.
+    assert!(max_switched_noise > max_fresh_noise);
+}
+
+#[test]
+fn test_key_switching_params_for_switch() {
+    type MsgT = u8;
+    type CipherT = u16;
+    type Modulus = PowOf2Modulus<CipherT>;
+
+    let mut rng = thread_rng();
+
+    let plain_modulus: CipherT = 4;
+    let log_modulus = 14;
+    let modulus = Modulus::new(1 << log_modulus);
+
+    let distr = Uniform::new(0, plain_modulus as MsgT);
+
+    let params_in =
+        LweParameters::new_unchecked(512, plain_modulus, modulus, LweSecretKeyType::Binary, 3.20);
+    let params_out =
+        LweParameters::new_unchecked(256, plain_modulus, modulus, LweSecretKeyType::Binary, 3.20);
+
+    let ks_params = KeySwitchingParameters::for_switch(&params_in, &params_out, 2, 6).unwrap();
+    assert_eq!(ks_params.input_cipher_dimension, 512);
+    assert_eq!(ks_params.output_cipher_dimension, 256);
+    assert_eq!(ks_params.log_modulus, log_modulus);
+    assert_eq!(ks_params.reverse_length, Some(6));
+
+    let full = KeySwitchingParameters::for_switch(&params_in, &params_out, 2, 7).unwrap();
+    assert_eq!(full.reverse_length, None);
+
+    let sk_a = LweSecretKey::generate(&params_in, &mut rng);
+    let sk_b = LweSecretKey::generate(&params_out, &mut rng);
+
+    let ksk = ks_params.generate_key(&sk_a, &sk_b, modulus, &mut rng);
+
+    for message in (&mut rng).sample_iter(distr).take(100) {
+        let c = params_in.encrypt(&sk_a, message, &mut rng);
+        let switched = ks_params.key_switch(&ksk, &c, modulus);
+        let m: MsgT = params_out.decrypt(&sk_b, &switched);
+        assert_eq!(m, message);
+    }
+
+    let other_modulus = Modulus::new(1 << (log_modulus - 1));
+    let params_other = LweParameters::new_unchecked(
+        256,
+        plain_modulus,
+        other_modulus,
+        LweSecretKeyType::Binary,
+        3.20,
+    );
+    assert!(matches!(
+        KeySwitchingParameters::for_switch(&params_in, &params_other, 2, 6),
+        Err(ParameterError::ModulusMismatch { .. })
+    ));
+
+    for (log_basis, levels) in [(0, 1), (2, 0), (2, 8), (15, 1)] {
+        assert!(matches!(
+            KeySwitchingParameters::for_switch(&params_in, &params_out, log_basis, levels),
+            Err(ParameterError::DecompositionNotValid { .. })
+        ));
+    }
+}
+
+#[test]
+fn test_lwe_params_modulus_switch() {
+    type MsgT = u8;
+    type CipherT = u16;
+    type Modulus = PowOf2Modulus<CipherT>;
+
+    let mut rng = thread_rng();
+
+    let plain_modulus: CipherT = 4;
+    let cipher_modulus: CipherT = 1 << 14;
+    let switched_modulus: CipherT = 1 << 11;
+
+    let distr = Uniform::new(0, plain_modulus as MsgT);
+
+    let params = LweParameters::new_unchecked(
+        512,
+        plain_modulus,
+        Modulus::new(cipher_modulus),
+        LweSecretKeyType::Binary,
+        3.20,
+    );
+    let switched_params = LweParameters::new_unchecked(
+        512,
+        plain_modulus,
+        Modulus::new(switched_modulus),
+        LweSecretKeyType::Binary,
+        3.20,
+    );
+
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    for message in (&mut rng).sample_iter(distr).take(100) {
+        let c = params.encrypt(&sk, message, &mut rng);
+        let switched: Lwe<CipherT> =
+            params.modulus_switch(&c, ModulusValue::PowerOf2(switched_modulus));
+        assert!(switched.a().iter().all(|&v| v < switched_modulus));
+        assert!(switched.b() < switched_modulus);
+
+        let m: MsgT = switched_params.decrypt(&sk, &switched);
+        assert_eq!(m, message);
+    }
+}
+
+#[test]
+fn test_lwe_params_modulus_switch_from_native() {
+    type MsgT = u8;
+    type CipherT = u16;
+
+    let mut rng = thread_rng();
+
+    let plain_modulus: CipherT = 4;
+    let switched_modulus: CipherT = 1 << 11;
+
+    let distr = Uniform::new(0, plain_modulus as MsgT);
+
+    let params = LweParameters::new_unchecked(
+        512,
+        plain_modulus,
+        NativeModulus::<CipherT>::new(),
+        LweSecretKeyType::Binary,
+        3.20,
+    );
+    let switched_params = LweParameters::new_unchecked(
+        512,
+        plain_modulus,
+        PowOf2Modulus::new(switched_modulus),
+        LweSecretKeyType::Binary,
+        3.20,
+    );
+
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    for message in (&mut rng).sample_iter(distr).take(100) {
+        let c = params.encrypt(&sk, message, &mut rng);
+        let switched: Lwe<CipherT> =
+            params.modulus_switch(&c, ModulusValue::PowerOf2(switched_modulus));
+        assert!(switched.a().iter().all(|&v| v < switched_modulus));
+        assert!(switched.b() < switched_modulus);
+
+        let m: MsgT = switched_params.decrypt(&sk, &switched);
+        assert_eq!(m, message);
+
+        // switching to the same native modulus keeps the ciphertext
+        let same: Lwe<CipherT> = params.modulus_switch(&c, ModulusValue::Native);
+        assert_eq!(same.a(), c.a());
+        assert_eq!(same.b(), c.b());
+    }
+}
+
+#[test]
+fn test_lwe_prime_modulus() {
+    type MsgT = u8;
+    type CipherT = u64;
+
+    let mut rng = thread_rng();
+
+    let plain_modulus: CipherT = 16;
+    let switched_modulus: u16 = 1 << 14;
+
+    let distr = Uniform::new(0, plain_modulus as MsgT);
+
+    let params = LweParameters::new_unchecked(
+        512,
+        plain_modulus,
+        GoldilocksModulus::new(),
+        LweSecretKeyType::Ternary,
+        3.20,
+    );
+    assert_eq!(
+        params.cipher_modulus_value,
+        ModulusValue::Prime(GoldilocksModulus::VALUE)
+    );
+
+    let switched_params = LweParameters::new_unchecked(
+        512,
+        plain_modulus as u16,
+        PowOf2Modulus::<u16>::new(switched_modulus),
+        LweSecretKeyType::Ternary,
+        3.20,
+    );
+
+    let sk = LweSecretKey::generate(&params, &mut rng);
+    let switched_sk = LweSecretKey::new(
+        sk.as_ref()
+            .iter()
+            .map(|&s| match s {
+                0 => 0,
+                1 => 1,
+                _ => switched_modulus - 1,
+            })
+            .collect(),
+        sk.distr(),
+    );
+
+    for message in (&mut rng).sample_iter(distr).take(100) {
+        let c = params.encrypt(&sk, message, &mut rng);
+        let m: MsgT = params.decrypt(&sk, &c);
+        assert_eq!(m, message);
+
+        let switched: Lwe<u16> =
+            params.modulus_switch(&c, ModulusValue::PowerOf2(switched_modulus));
+        let m: MsgT = switched_params.decrypt(&switched_sk, &switched);
+        assert_eq!(m, message);
+    }
+}
+
+/// A coarse dudect style check: decrypting two fixed ciphertexts of different
+/// messages should not be distinguishable by its running time.
+#[test]
+#[ignore = "timing test, run with `cargo test --release -- --ignored`"]
+fn test_lwe_decrypt_constant_time() {
+    use std::{hint::black_box, time::Instant};
+
+    type MsgT = u8;
+    type CipherT = u16;
+    type Modulus = PowOf2Modulus<CipherT>;
+
+    const SAMPLES: usize = 20000;
+    const BATCH: usize = 16;
+
+    let mut rng = thread_rng();
+
+    let params = LweParameters::new_unchecked(
+        512,
+        4 as CipherT,
+        Modulus::new(1 << 14),
+        LweSecretKeyType::Ternary,
+        3.20,
+    );
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    let ciphertexts = [
+        params.encrypt(&sk, 0 as MsgT, &mut rng),
+        params.encrypt(&sk, 3 as MsgT, &mut rng),
+    ];
+
+    let mut timings: [Vec<f64>; 2] = [Vec::new(), Vec::new()];
+    for _ in 0..SAMPLES {
+        let class = rng.gen_range(0..2);
+        let c = &ciphertexts[class];
+        let start = Instant::now();
+        for _ in 0..BATCH {
+            let m: MsgT = sk.decrypt(black_box(c), &params);
+            black_box(m);
+        }
+        timings[class].push(start.elapsed().as_nanos() as f64);
+    }
+
+    let (mean_0, var_0) = mean_variance(&timings[0]);
+    let (mean_1, var_1) = mean_variance(&timings[1]);
+    let t = (mean_0 - mean_1)
+        / (var_0 / timings[0].len() as f64 + var_1 / timings[1].len() as f64).sqrt();
+
+    // dudect treats |t| > 10 as a definite leak.
+    assert!(t.abs() < 10.0, "welch t-statistic {t}");
+}
+
+fn mean_variance(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let var = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0);
+    (mean, var)
+}
+
+#[test]
+fn test_gadget_rlwe_params_ntt() {
+    type FieldT = U32FieldEval<132120577>;
+    type ValT = u32;
+    type PolyT = FieldPolynomial<FieldT>;
+
+    const N: usize = 256;
+
+    let mut rng = thread_rng();
+
+    let params = GadgetRlweParameters::<FieldT> {
+        dimension: N,
+        modulus: FieldT::MODULUS_VALUE,
+        secret_key_type: RingSecretKeyType::Ternary,
+        noise_standard_deviation: 3.20,
+        basis: NonPowOf2ApproxSignedBasis::new(FieldT::MODULUS_VALUE, 4, None),
+        noise_distribution_type: NoiseDistributionType::Gaussian,
+    };
+    let ntt_table = params.generate_ntt_table().unwrap();
+    // the table is cached
+    assert!(Arc::ptr_eq(
+        &ntt_table,
+        &params.generate_ntt_table().unwrap()
+    ));
+
+    let a = PolyT::random(N, &mut rng);
+    let b = PolyT::random(N, &mut rng);
+
+    // round trip
+    let a_ntt = ntt_table.transform(&a);
+    assert_eq!(ntt_table.inverse_transform(&a_ntt), a);
+
+    // negacyclic schoolbook multiplication
+    let mut expected = vec![0 as ValT; N];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let product = FieldT::mul(x, y);
+            let k = i + j;
+            if k < N {
+                FieldT::add_assign(&mut expected[k], product);
+            } else {
+                FieldT::sub_assign(&mut expected[k - N], product);
+            }
+        }
+    }
+
+    let product = ntt_table.inverse_transform_inplace(a_ntt * ntt_table.transform(&b));
+    assert_eq!(product, PolyT::new(expected));
+}
+
+#[test]
+fn test_seeded_rng_reproducibility() {
+    type FieldT = U32FieldEval<132120577>;
+    type PolyT = FieldPolynomial<FieldT>;
+
+    const LOG_N: u32 = 8;
+    const N: usize = 1 << LOG_N;
+
+    let lwe_params = LweParameters::new_unchecked(
+        512,
+        8u16,
+        PowOf2Modulus::<u16>::new(2048),
+        LweSecretKeyType::Ternary,
+        3.20,
+    );
+    let rlwe_params = GadgetRlweParameters::<FieldT> {
+        dimension: N,
+        modulus: FieldT::MODULUS_VALUE,
+        secret_key_type: RingSecretKeyType::Ternary,
+        noise_standard_deviation: 3.20,
+        basis: NonPowOf2ApproxSignedBasis::new(FieldT::MODULUS_VALUE, 4, None),
+        noise_distribution_type: NoiseDistributionType::Gaussian,
+    };
+    let ntt_table = rlwe_params.generate_ntt_table().unwrap();
+    let message = PolyT::new((0..N as u32).collect());
+
+    let run = |seed: u64| {
+        let mut rng = Prg::seed_from_u64(seed);
+
+        let lwe_sk = LweSecretKey::generate(&lwe_params, &mut rng);
+        let lwe_ct = lwe_params.encrypt(&lwe_sk, 3u8, &mut rng);
+
+        let rlwe_sk = rlwe_params.generate_secret_key(&mut rng);
+        let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&rlwe_sk, &ntt_table);
+        let rlwe_ct = rlwe_params.encrypt_poly(&ntt_sk, &message, &ntt_table, &mut rng);
+
+        (lwe_ct, rlwe_ct)
+    };
+
+    // same seed, identical ciphertexts
+    let (lwe_1, rlwe_1) = run(1);
+    let (lwe_1_again, rlwe_1_again) = run(1);
+    assert_eq!(lwe_1, lwe_1_again);
+    assert!(rlwe_1 == rlwe_1_again);
+
+    // different seeds, different ciphertexts
+    let (lwe_2, rlwe_2) = run(2);
+    assert_ne!(lwe_1, lwe_2);
+    assert!(rlwe_1 != rlwe_2);
+}
+
+#[test]
+fn test_lwe_params_encrypt_batch() {
+    type MsgT = u8;
+    type CipherT = u16;
+
+    const BATCH: usize = 10_000;
+
+    let mut rng = thread_rng();
+
+    let plain_modulus: CipherT = 8;
+    let distr = Uniform::new(0, plain_modulus as MsgT);
+
+    let params = LweParameters::new_unchecked(
+        512,
+        plain_modulus,
+        PowOf2Modulus::<CipherT>::new(2048),
+        LweSecretKeyType::Ternary,
+        3.20,
+    );
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    let messages: Vec<MsgT> = (&mut rng).sample_iter(distr).take(BATCH).collect();
+    let ciphertexts = params.encrypt_batch(&sk, &messages, &mut rng);
+    assert_eq!(ciphertexts.len(), BATCH);
+
+    for (c, &message) in ciphertexts.iter().zip(messages.iter()) {
+        let m: MsgT = params.decrypt(&sk, c);
+        assert_eq!(m, message);
+    }
+
+    // every ciphertext has its own randomness
+    assert_ne!(ciphertexts[0].a(), ciphertexts[1].a());
+}
+
+#[test]
+fn test_gadget_rlwe_params_sample_extract() {
+    type FieldT = U32FieldEval<132120577>;
+    type ValT = u32;
+    type PolyT = FieldPolynomial<FieldT>;
+
+    const LOG_N: u32 = 10;
+    const N: usize = 1 << LOG_N;
+    const PLAIN_MODULUS: ValT = 8;
+
+    let mut rng = thread_rng();
+
+    let rlwe_params = GadgetRlweParameters::<FieldT> {
+        dimension: N,
+        modulus: FieldT::MODULUS_VALUE,
+        secret_key_type: RingSecretKeyType::Ternary,
+        noise_standard_deviation: 3.20,
+        basis: NonPowOf2ApproxSignedBasis::new(FieldT::MODULUS_VALUE, 4, None),
+        noise_distribution_type: NoiseDistributionType::Gaussian,
+    };
+    let lwe_params = LweParameters::new_unchecked(
+        N,
+        PLAIN_MODULUS,
+        FieldT::MODULUS,
+        LweSecretKeyType::Ternary,
+        3.20,
+    );
+    let ntt_table = rlwe_params.generate_ntt_table().unwrap();
+
+    let sk = rlwe_params.generate_secret_key(&mut rng);
+    let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+    let lwe_sk = LweSecretKey::from_rlwe_secret_key(&sk, FieldT::MINUS_ONE);
+
+    let encode = |m: ValT| -> ValT {
+        (m as f64 * FieldT::MODULUS_VALUE as f64 / PLAIN_MODULUS as f64).round() as ValT
+    };
+    let messages: Vec<ValT> = (0..N as ValT).map(|i| (i * 3) % PLAIN_MODULUS).collect();
+    let cipher = rlwe_params.encrypt_poly(
+        &ntt_sk,
+        &PolyT::new(messages.iter().copied().map(encode).collect()),
+        &ntt_table,
+        &mut rng,
+    );
+
+    for index in [0, 1, 2, N / 2, N - 2, N - 1] {
+        let lwe = rlwe_params.sample_extract(&cipher, index);
+        assert_eq!(lwe.a().len(), N);
+        let m: ValT = lwe_params.decrypt(&lwe_sk, &lwe);
+        assert_eq!(m, messages[index]);
+    }
+}
+
+#[test]
+fn test_lwe_params_validate() {
+    type CipherT = u16;
+    type Modulus = PowOf2Modulus<CipherT>;
+
+    let params = |dimension: usize, plain: CipherT, q: CipherT, sigma: f64| {
+        LweParameters::new_unchecked(
+            dimension,
+            plain,
+            Modulus::new(q),
+            LweSecretKeyType::Binary,
+            sigma,
+        )
+    };
+
+    // known good
+    assert_eq!(params(512, 4, 1 << 14, 3.20).validate(), Ok(()));
+    assert_eq!(params(512, 8, 2048, 3.20).validate(), Ok(()));
+    let goldilocks = LweParameters::new_unchecked(
+        1024,
+        1u64 << 16,
+        GoldilocksModulus::new(),
+        LweSecretKeyType::Ternary,
+        3.20,
+    );
+    assert_eq!(goldilocks.validate(), Ok(()));
+    assert!(params(512, 4, 1 << 14, 3.20).decryption_failure_log2_probability() < -1000.0);
+
+    // known bad
+    assert_eq!(
+        params(0, 4, 1 << 14, 3.20).validate(),
+        Err(ParameterError::DimensionNotValid(0))
+    );
+    assert!(matches!(
+        params(512, 1, 1 << 14, 3.20).validate(),
+        Err(ParameterError::PlainModulusNotValid { .. })
+    ));
+    assert!(matches!(
+        params(512, 1 << 14, 1 << 14, 3.20).validate(),
+        Err(ParameterError::PlainModulusNotValid { .. })
+    ));
+    assert_eq!(
+        params(512, 4, 1 << 14, 0.0).validate(),
+        Err(ParameterError::NoiseNotValid(0.0))
+    );
+    assert!(matches!(
+        params(512, 4, 1 << 14, f64::NAN).validate(),
+        Err(ParameterError::NoiseNotValid(_))
+    ));
+    // q/2t = 8 is only 2.5 standard deviations
+    assert!(matches!(
+        params(512, 64, 1024, 3.20).validate(),
+        Err(ParameterError::DecryptionFailureTooLikely(_))
+    ));
+}
+
+#[test]
+fn test_lwe_params_try_new() {
+    let tfhe = LweParameters::preset_128bit_tfhe();
+    let preset = |security_bits| {
+        LweParameters::try_new(
+            tfhe.dimension,
+            tfhe.plain_modulus_value,
+            tfhe.cipher_modulus,
+            tfhe.secret_key_type,
+            tfhe.noise_standard_deviation,
+            security_bits,
+        )
+    };
+
+    assert!(preset(128.0).is_ok());
+    assert!(matches!(
+        preset(160.0),
+        Err(ParameterError::SecurityTooLow { .. })
+    ));
+
+    assert!(LweParameters::try_new(
+        1024,
+        1u64 << 16,
+        GoldilocksModulus::new(),
+        LweSecretKeyType::Ternary,
+        1u64 << 40,
+        100.0,
+    )
+    .is_ok());
+
+    // a larger secret key makes the same noise relatively smaller,
+    // a larger modulus makes the attack easier.
+    let with_key_type = |secret_key_type| {
+        LweParameters::new_unchecked(
+            512,
+            4,
+            PowOf2Modulus::<u16>::new(1 << 14),
+            secret_key_type,
+            3.20,
+        )
+        .estimated_security_bits()
+    };
+    assert!(with_key_type(LweSecretKeyType::Ternary) > with_key_type(LweSecretKeyType::Binary));
+
+    // dimension 10 with a 60 bit modulus
+    assert!(matches!(
+        LweParameters::try_new(
+            10,
+            4,
+            PowOf2Modulus::<u64>::new(1 << 60),
+            LweSecretKeyType::Binary,
+            3.20,
+            80.0,
+        ),
+        Err(ParameterError::SecurityTooLow { .. })
+    ));
+    // dimension 256 with a 14 bit modulus
+    assert!(matches!(
+        LweParameters::try_new(
+            256,
+            4,
+            PowOf2Modulus::<u16>::new(1 << 14),
+            LweSecretKeyType::Binary,
+            3.20,
+            80.0,
+        ),
+        Err(ParameterError::SecurityTooLow { .. })
+    ));
+    // `validate` runs first
+    assert_eq!(
+        LweParameters::try_new(
+            0,
+            4,
+            PowOf2Modulus::<u16>::new(1 << 14),
+            LweSecretKeyType::Binary,
+            3.20,
+            80.0,
+        )
+        .err(),
+        Some(ParameterError::DimensionNotValid(0))
+    );
+}
+
+#[test]
+fn test_gadget_rlwe_params_validate() {
+    type FieldT = U32FieldEval<132120577>;
+
+    let params = |dimension: usize, sigma: f64| GadgetRlweParameters::<FieldT> {
+        dimension,
+        modulus: FieldT::MODULUS_VALUE,
+        secret_key_type: RingSecretKeyType::Ternary,
+        noise_standard_deviation: sigma,
+        basis: NonPowOf2ApproxSignedBasis::new(FieldT::MODULUS_VALUE, 7, None),
+        noise_distribution_type: NoiseDistributionType::Gaussian,
+    };
+
+    assert_eq!(params(1024, 3.20).validate(), Ok(()));
+
+    assert_eq!(
+        params(1000, 3.20).validate(),
+        Err(ParameterError::DimensionNotValid(1000))
+    );
+    assert_eq!(
+        params(0, 3.20).validate(),
+        Err(ParameterError::DimensionNotValid(0))
+    );
+    assert_eq!(
+        params(1024, 0.5).validate(),
+        Err(ParameterError::NoiseNotValid(0.5))
+    );
+
+    let mut wrong_modulus = params(1024, 3.20);
+    wrong_modulus.modulus = 1000003;
+    assert!(matches!(
+        wrong_modulus.validate(),
+        Err(ParameterError::ModulusNotValid(_))
+    ));
+}
+
+#[test]
+fn test_lwe_preset_128bit_tfhe() {
+    let mut rng = thread_rng();
+
+    let params = LweParameters::preset_128bit_tfhe();
+    assert_eq!(params.validate(), Ok(()));
+    assert!(params.estimated_security_bits() >= 128.0);
+
+    let sk = LweSecretKey::generate(&params, &mut rng);
+    for m in 0..4u8 {
+        let c = params.encrypt(&sk, m, &mut rng);
+        let decrypted: u8 = params.decrypt(&sk, &c);
+        assert_eq!(decrypted, m);
+    }
+}
+
+type PresetField = U32FieldEval<132120577>;
+
+/// The [`GadgetRlweParameters::preset_128bit_tfhe`] parameters with their NTT table and a fresh secret key.
+struct PresetRlwe {
+    params: GadgetRlweParameters<PresetField>,
+    ntt_table: Arc<<PresetField as NttField>::Table>,
+    sk: RlweSecretKey<PresetField>,
+    ntt_sk: NttRlweSecretKey<PresetField>,
+}
+
+impl PresetRlwe {
+    fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        let params = GadgetRlweParameters::preset_128bit_tfhe();
+        let ntt_table = params.generate_ntt_table().unwrap();
+        let sk = params.generate_secret_key(rng);
+        let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+        Self {
+            params,
+            ntt_table,
+            sk,
+            ntt_sk,
+        }
+    }
+
+    fn encrypt<R: Rng + CryptoRng>(
+        &self,
+        messages: &[u32],
+        plain_modulus: u32,
+        rng: &mut R,
+    ) -> RlweCiphertext<PresetField> {
+        self.params
+            .encrypt_packed(&self.ntt_sk, messages, plain_modulus, &self.ntt_table, rng)
+    }
+
+    fn decrypt(&self, cipher: &RlweCiphertext<PresetField>, plain_modulus: u32) -> Vec<u32> {
+        self.params
+            .decrypt_packed(&self.ntt_sk, cipher, plain_modulus, &self.ntt_table)
+    }
+}
+
+/// Encodes `messages` into a plaintext polynomial of [`PresetField`].
+fn encode_preset_poly(messages: &[u32], plain_modulus: u32) -> FieldPolynomial<PresetField> {
+    let cipher_modulus = ModulusValue::Prime(PresetField::MODULUS_VALUE);
+    FieldPolynomial::new(
+        messages
+            .iter()
+            .map(|&m| encode(m, plain_modulus, cipher_modulus))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_gadget_rlwe_preset_128bit_tfhe() {
+    const T: u32 = 4;
+
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, T);
+
+    let preset = PresetRlwe::new(&mut rng);
+    assert_eq!(preset.params.validate(), Ok(()));
+    assert!(preset.params.estimated_security_bits() >= 128.0);
+
+    let m: Vec<u32> = (&mut rng)
+        .sample_iter(distr)
+        .take(preset.params.dimension())
+        .collect();
+    let c = preset.encrypt(&m, T, &mut rng);
+    assert_eq!(preset.decrypt(&c, T), m);
+}
+
+#[test]
+fn test_gadget_rlwe_params_automorphism() {
+    const T: u32 = 4;
+
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, T);
+
+    let preset = PresetRlwe::new(&mut rng);
+    let params = &preset.params;
+    let n = params.dimension();
+    let twice_n = n << 1;
+
+    // `5 * inv_5 ≡ 1 (mod 2N)`, so `X -> X^5 -> X^(5 * inv_5)` is the identity.
+    let inv_5 = (1..twice_n)
+        .step_by(2)
+        .find(|k| k * 5 % twice_n == 1)
+        .unwrap();
+
+    let keys: Vec<_> = [5, inv_5, twice_n - 1]
+        .into_iter()
+        .map(|degree| {
+            params.generate_auto_key(
+                &preset.sk,
+                &preset.ntt_sk,
+                degree,
+                Arc::clone(&preset.ntt_table),
+                &mut rng,
+            )
+        })
+        .collect();
+
+    let m: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+    let cipher = preset.encrypt(&m, T, &mut rng);
+
+    // `m(X^5)` moves the `i`-th coefficient to `5i mod 2N`, negated past `N`.
+    let auto5 = params.apply_automorphism(&keys, &cipher, 5);
+    let decrypted = preset.decrypt(&auto5, T);
+    for (i, &mi) in m.iter().enumerate() {
+        let j = i * 5 % twice_n;
+        if j < n {
+            assert_eq!(decrypted[j], mi);
+        } else {
+            assert_eq!(decrypted[j - n], (T - mi) % T);
+        }
+    }
+
+    let identity = params.apply_automorphism(&keys, &auto5, inv_5);
+    assert_eq!(preset.decrypt(&identity, T), m);
+
+    let inverse = params.apply_automorphism(&keys, &cipher, twice_n - 1);
+    let identity = params.apply_automorphism(&keys, &inverse, twice_n - 1);
+    assert_eq!(preset.decrypt(&identity, T), m);
+}
+
+#[test]
+fn test_encode_f64_precision() {
+    fn check<C: algebra::integer::UnsignedInteger>(q: ModulusValue<C>) {
+        for scale in [1.0, 3.0, 16.0, 1000.0, 65536.0] {
+            for i in -1000..=1000 {
+                let value = i as f64 * 0.1237;
+                let decoded = decode_f64(encode_f64(value, scale, q), scale, q);
+                assert!(
+                    (decoded - value).abs() <= 1.0 / scale,
+                    "value {value}, scale {scale}, decoded {decoded}"
+                );
+            }
+        }
+    }
+
+    check(ModulusValue::<u32>::Native);
+    check(ModulusValue::<u32>::PowerOf2(1 << 30));
+    check(ModulusValue::<u32>::Prime(132120577));
+    check(ModulusValue::<u64>::Prime(GoldilocksModulus::VALUE));
+
+    // ties are rounded away from zero
+    let q = ModulusValue::<u32>::Prime(132120577);
+    assert_eq!(encode_f64(2.5, 1.0, q), 3);
+    assert_eq!(encode_f64(-2.5, 1.0, q), 132120577 - 3);
+    assert_eq!(decode_f64(encode_f64(-2.5, 1.0, q), 1.0, q), -3.0);
+    assert_eq!(encode_f64(-1.0, 1.0, ModulusValue::<u32>::Native), u32::MAX);
+}
+
+#[test]
+#[should_panic]
+fn test_encode_f64_overflow() {
+    encode_f64(
+        1.0,
+        (1 << 13) as f64,
+        ModulusValue::<u16>::PowerOf2(1 << 14),
+    );
+}
+
+#[test]
+fn test_lwe_params_encrypt_f64() {
+    let mut rng = thread_rng();
+
+    let params = LweParameters::preset_128bit_tfhe();
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    let scale = 64.0;
+    for _ in 0..100 {
+        let value: f64 = rng.gen_range(-100.0..100.0);
+        let c = params.encrypt_f64(&sk, value, scale, &mut rng);
+        let decrypted = params.decrypt_f64(&sk, &c, scale);
+        // the noise is far below `10σ = 32`
+        assert!((decrypted - value).abs() <= 32.5 / scale);
+    }
+}
+
+#[test]
+fn test_lwe_params_plaintext_arithmetic() {
+    let mut rng = thread_rng();
+
+    let params = LweParameters::preset_128bit_tfhe();
+    let q = params.cipher_modulus();
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    assert!(params.fits_plaintext(3));
+    assert!(!params.fits_plaintext(4));
+
+    // results wrap around `t = 4`
+    assert_eq!(params.plain_add(3, 2), 1);
+    assert_eq!(params.plain_add(2, 2), 0);
+    assert_eq!(params.plain_mul(3, 3), 1);
+    assert_eq!(params.plain_mul(2, 2), 0);
+
+    for a in 0..4u16 {
+        for b in 0..4u16 {
+            let ca = params.encrypt(&sk, a, &mut rng);
+            let cb = params.encrypt(&sk, b, &mut rng);
+
+            let sum = ca.add_reduce_component_wise_ref(&cb, q);
+            let decrypted: u16 = params.decrypt(&sk, &sum);
+            assert_eq!(decrypted, params.plain_add(a, b));
+
+            let mut product = ca;
+            product.mul_scalar_reduce_assign(b, q);
+            let decrypted: u16 = params.decrypt(&sk, &product);
+            assert_eq!(decrypted, params.plain_mul(a, b));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_lwe_params_plain_add_out_of_range() {
+    LweParameters::preset_128bit_tfhe().plain_add(4, 1);
+}
+
+#[test]
+fn test_lwe_params_encode_with_policy() {
+    let params = LweParameters::preset_128bit_tfhe();
+    let t = params.plain_modulus_value();
+    let q = params.cipher_modulus_value();
+
+    for policy in [
+        OverflowPolicy::Error,
+        OverflowPolicy::Wrap,
+        OverflowPolicy::Saturate,
+    ] {
+        for m in 0..t {
+            assert_eq!(
+                params.encode_with_policy(m, policy).unwrap(),
+                encode(m, t, q)
+            );
+        }
+    }
+
+    for m in [t, t + 1, 3 * t - 1, u16::MAX] {
+        assert!(matches!(
+            params.encode_with_policy(m, OverflowPolicy::Error),
+            Err(FHECoreError::MessageOutOfRange { .. })
+        ));
+        assert_eq!(
+            params.encode_with_policy(m, OverflowPolicy::Wrap).unwrap(),
+            encode(m % t, t, q)
+        );
+        assert_eq!(
+            params
+                .encode_with_policy(m, OverflowPolicy::Saturate)
+                .unwrap(),
+            encode(t - 1, t, q)
+        );
+    }
+}
+
+#[test]
+fn test_encode_with_policy_round_trip() {
+    let moduli: [(u32, ModulusValue<u32>); 3] = [
+        (4, ModulusValue::PowerOf2(1 << 14)),
+        (4, ModulusValue::Native),
+        (5, ModulusValue::Prime(132120577)),
+    ];
+
+    for (t, q) in moduli {
+        for m in 0..3 * t {
+            for policy in [
+                OverflowPolicy::Error,
+                OverflowPolicy::Wrap,
+                OverflowPolicy::Saturate,
+            ] {
+                let expected = if m < t {
+                    Some(m)
+                } else {
+                    match policy {
+                        OverflowPolicy::Error => None,
+                        OverflowPolicy::Wrap => Some(m % t),
+                        OverflowPolicy::Saturate => Some(t - 1),
+                    }
+                };
+
+                let decoded = encode_with_policy(m, t, q, policy)
+                    .ok()
+                    .map(|c| decode::<u32, u32>(c, t, q));
+                assert_eq!(decoded, expected, "m {m}, t {t}, {q:?}, {policy:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gadget_rlwe_params_external_product() {
+    const T: u32 = 4;
+
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, T);
+
+    let preset = PresetRlwe::new(&mut rng);
+    let params = &preset.params;
+    let ntt_table = &preset.ntt_table;
+    let n = params.dimension();
+
+    let m0: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+    let m1: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+    let c0 = preset.encrypt(&m0, T, &mut rng);
+    let c1 = preset.encrypt(&m1, T, &mut rng);
+
+    for bit in [false, true] {
+        let rgsw = params.encrypt_rgsw_bit(&preset.ntt_sk, bit, ntt_table, &mut rng);
+
+        let product = params.external_product(&rgsw, &c0, ntt_table);
+        let expected = if bit { m0.clone() } else { vec![0; n] };
+        assert_eq!(preset.decrypt(&product, T), expected);
+
+        // CMux(bit, c0, c1) = c0 + bit ⊡ (c1 - c0)
+        let diff = c1.clone().sub_element_wise(&c0);
+        let cmux = params
+            .external_product(&rgsw, &diff, ntt_table)
+            .add_element_wise(&c0);
+        let expected = if bit { &m1 } else { &m0 };
+        assert_eq!(&preset.decrypt(&cmux, T), expected);
+    }
+}
+
+#[test]
+fn test_gadget_rlwe_params_blind_rotate() {
+    const T: u32 = 8;
+    const LWE_DIMENSION: usize = 16;
+
+    let mut rng = thread_rng();
+
+    let preset = PresetRlwe::new(&mut rng);
+    let params = &preset.params;
+    let ntt_table = &preset.ntt_table;
+    let n = params.dimension();
+    let twice_n = n << 1;
+
+    let lwe_sk: Vec<bool> = (0..LWE_DIMENSION).map(|_| rng.gen()).collect();
+    let keys: Vec<_> = lwe_sk
+        .iter()
+        .map(|&bit| params.encrypt_rgsw_bit(&preset.ntt_sk, bit, ntt_table, &mut rng))
+        .collect();
+
+    let lut: Vec<u32> = (0..n as u32).map(|i| i % T).collect();
+
+    // the rotated `lut * X^(-phase)` message
+    let rotated = |phase: usize| -> Vec<u32> {
+        (0..n)
+            .map(|k| {
+                let j = (k + phase) % twice_n;
+                if j < n {
+                    lut[j]
+                } else {
+                    (T - lut[j - n]) % T
+                }
+            })
+            .collect()
+    };
+
+    for phase in [0, 1, 7, n - 1, n, n + 5, twice_n - 1] {
+        // a noiseless LWE ciphertext modulo `2N` with `b - <a, s> = phase`
+        let a: Vec<u16> = (0..LWE_DIMENSION)
+            .map(|_| rng.gen_range(0..twice_n as u16))
+            .collect();
+        let a_mul_s: usize = a
+            .iter()
+            .zip(&lwe_sk)
+            .map(|(&ai, &si)| if si { ai as usize } else { 0 })
+            .sum();
+        let b = ((phase + a_mul_s) % twice_n) as u16;
+        let cipher = Lwe::new(a, b);
+
+        let acc = params.blind_rotate(&keys, encode_preset_poly(&lut, T), &cipher, ntt_table);
+        assert_eq!(preset.decrypt(&acc, T), rotated(phase), "phase {phase}");
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_gadget_rlwe_params_blind_rotate_unswitched() {
+    let mut rng = thread_rng();
+
+    let preset = PresetRlwe::new(&mut rng);
+    let params = &preset.params;
+    let twice_n = (params.dimension() << 1) as u32;
+
+    let keys = vec![params.encrypt_rgsw_bit(&preset.ntt_sk, true, &preset.ntt_table, &mut rng)];
+
+    // `b` is still modulo `Q`, not switched to `2N`
+    let cipher = Lwe::new(vec![1u32], twice_n);
+    params.blind_rotate(
+        &keys,
+        FieldPolynomial::zero(params.dimension()),
+        &cipher,
+        &preset.ntt_table,
+    );
+}
+
+#[test]
+fn test_lwe_params_secret_key_distribution() {
+    let mut rng = thread_rng();
+
+    let mut params = LweParameters::preset_128bit_tfhe();
+    let minus_one = params.cipher_modulus_minus_one();
+
+    for secret_key_type in [LweSecretKeyType::Binary, LweSecretKeyType::Ternary] {
+        params.secret_key_type = secret_key_type;
+
+        let sampler = params.secret_key_distribution();
+        assert_eq!(sampler.secret_key_type(), secret_key_type);
+
+        let sk = LweSecretKey::generate(&params, &mut rng);
+        assert_eq!(sk.distr(), secret_key_type);
+        assert!(sk.as_ref().iter().all(|&s| match secret_key_type {
+            LweSecretKeyType::Binary => s <= 1,
+            LweSecretKeyType::Ternary => s <= 1 || s == minus_one,
+        }));
+    }
+}
+
+#[test]
+fn test_lwe_params_ternary_noise() {
+    type Modulus = PowOf2Modulus<u16>;
+
+    let mut rng = thread_rng();
+
+    let plain_modulus: u16 = 8;
+    let cipher_modulus: u16 = 2048;
+    let minus_one = cipher_modulus - 1;
+
+    let mut params = LweParameters::new_unchecked(
+        512,
+        plain_modulus,
+        Modulus::new(cipher_modulus),
+        LweSecretKeyType::Binary,
+        3.20,
+    );
+    params.noise_distribution_type = NoiseDistributionType::Ternary;
+    assert_eq!(params.validate(), Ok(()));
+    assert_eq!(
+        params.noise_distribution().noise_distribution_type(),
+        NoiseDistributionType::Ternary
+    );
+
+    let sk = LweSecretKey::generate(&params, &mut rng);
+    let q = params.cipher_modulus;
+    for message in 0..plain_modulus {
+        let c = params.encrypt(&sk, message, &mut rng);
+        assert_eq!(params.decrypt::<u16>(&sk, &c), message);
+
+        // `q / t = 2⁸`, the noise is `0`, `1` or `-1`
+        let phase = q.reduce_sub(c.b(), q.reduce_dot_product(c.a(), &sk));
+        let e = q.reduce_sub(phase, message << 8);
+        assert!(e <= 1 || e == minus_one);
+    }
+}
+
+#[test]
+fn test_gadget_rlwe_params_ternary_noise() {
+    const T: u32 = 4;
+
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, T);
+
+    let mut params = GadgetRlweParameters::<PresetField>::preset_128bit_tfhe();
+    params.noise_distribution_type = NoiseDistributionType::Ternary;
+    assert_eq!(params.validate(), Ok(()));
+
+    let ntt_table = params.generate_ntt_table().unwrap();
+    let sk = params.generate_secret_key(&mut rng);
+    let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+
+    // the phase of an encryption of zero is its noise
+    let n = params.dimension();
+    let zero = FieldPolynomial::<PresetField>::zero(n);
+    let cipher = params.encrypt_poly(&ntt_sk, &zero, &ntt_table, &mut rng);
+    let minus_one = PresetField::MODULUS_VALUE - 1;
+    assert!(params
+        .decrypt_poly(&ntt_sk, &cipher, &ntt_table)
+        .into_iter()
+        .all(|e| e <= 1 || e == minus_one));
+
+    // the automorphism keys are sampled with the same noise
+    let twice_n = n << 1;
+    let keys =
+        vec![params.generate_auto_key(&sk, &ntt_sk, twice_n - 1, Arc::clone(&ntt_table), &mut rng)];
+
+    let m: Vec<u32> = (&mut rng).sample_iter(distr).take(n).collect();
+    let cipher = params.encrypt_packed(&ntt_sk, &m, T, &ntt_table, &mut rng);
+    let inverse = params.apply_automorphism(&keys, &cipher, twice_n - 1);
+    let identity = params.apply_automorphism(&keys, &inverse, twice_n - 1);
+    assert_eq!(
+        params.decrypt_packed::<u32>(&ntt_sk, &identity, T, &ntt_table),
+        m
+    );
+}
+
+#[test]
+fn test_lwe_secret_key_new() {
+    let sk = LweSecretKey::new(vec![0u16, 1, 16383, 1], LweSecretKeyType::Ternary);
+    assert_eq!(sk.dimension(), 4);
+    let sk = LweSecretKey::new(vec![0u16, 1, 1, 0], LweSecretKeyType::Binary);
+    assert_eq!(sk.distr(), LweSecretKeyType::Binary);
+}
+
+#[test]
+#[should_panic]
+fn test_lwe_secret_key_new_binary_out_of_range() {
+    LweSecretKey::new(vec![0u16, 1, 16383], LweSecretKeyType::Binary);
+}
+
+#[test]
+#[should_panic]
+fn test_lwe_secret_key_new_ternary_out_of_range() {
+    LweSecretKey::new(vec![0u16, 16383, 2], LweSecretKeyType::Ternary);
+}
+
+#[test]
+fn test_gadget_rlwe_params_packed() {
+    const PLAIN_MODULUS: u32 = 16;
+
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, PLAIN_MODULUS);
+
+    let preset = PresetRlwe::new(&mut rng);
+    let n = preset.params.dimension();
+
+    let padded = |m: &[u32]| -> Vec<u32> {
+        let mut v = m.to_vec();
+        v.resize(n, 0);
+        v
+    };
+
+    for len in [0, 1, 7, n / 2, n - 1, n] {
+        let m1: Vec<u32> = (&mut rng).sample_iter(distr).take(len).collect();
+        let m2: Vec<u32> = (&mut rng).sample_iter(distr).take(len).collect();
+
+        let c1 = preset.encrypt(&m1, PLAIN_MODULUS, &mut rng);
+        let c2 = preset.encrypt(&m2, PLAIN_MODULUS, &mut rng);
+        assert_eq!(
+            preset.decrypt(&c1, PLAIN_MODULUS),
+            padded(&m1),
+            "length {len}"
+        );
+
+        let sum = c1.add_element_wise(&c2);
+        let expected: Vec<u32> = m1
+            .iter()
+            .zip(&m2)
+            .map(|(a, b)| (a + b) % PLAIN_MODULUS)
+            .collect();
+        assert_eq!(
+            preset.decrypt(&sum, PLAIN_MODULUS),
+            padded(&expected),
+            "length {len}"
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_gadget_rlwe_params_packed_too_many() {
+    let mut rng = thread_rng();
+
+    let preset = PresetRlwe::new(&mut rng);
+    let messages = vec![0u32; preset.params.dimension() + 1];
+    preset.encrypt(&messages, 16, &mut rng);
+}
+
+#[test]
+fn test_lwe_params_linear_combine() {
+    let mut rng = thread_rng();
+
+    let params = LweParameters::preset_128bit_tfhe();
+    let q = params.cipher_modulus();
+    let minus_one = params.cipher_modulus_minus_one();
+    let t = params.plain_modulus_value();
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    // scalars as messages modulo `t`, `q - 1` is `-1`
+    let plain = |c: u16| if c == minus_one { t - 1 } else { c % t };
+    let expected = |scalars: &[u16], messages: &[u16]| {
+        scalars.iter().zip(messages).fold(0, |acc, (&c, &m)| {
+            params.plain_add(acc, params.plain_mul(plain(c), m))
+        })
+    };
+
+    let empty = params.linear_combine(&[]);
+    assert_eq!(params.decrypt::<u16>(&sk, &empty), 0);
+
+    let cases: [&[u16]; 4] = [
+        &[1, 1],
+        &[3],
+        &[2, minus_one],
+        &[1, 2, 3, minus_one, 1, 0, 2, minus_one, 3, 1],
+    ];
+    for scalars in cases {
+        let predicted = params.linear_combine_noise_standard_deviation(scalars);
+
+        let mut squares = 0.0;
+        const TRIALS: usize = 500;
+        for _ in 0..TRIALS {
+            let messages: Vec<u16> = (0..scalars.len()).map(|_| rng.gen_range(0..t)).collect();
+            let ciphertexts: Vec<_> = messages
+                .iter()
+                .map(|&m| params.encrypt(&sk, m, &mut rng))
+                .collect();
+            let terms: Vec<_> = scalars.iter().copied().zip(&ciphertexts).collect();
+
+            let combined = params.linear_combine(&terms);
+            let m = expected(scalars, &messages);
+            assert_eq!(params.decrypt::<u16>(&sk, &combined), m);
+
+            // `q / t = 2¹²`
+            let phase = q.reduce_sub(combined.b(), q.reduce_dot_product(combined.a(), &sk));
+            let e = q.reduce_sub(phase, m << 12);
+            let e = if e > minus_one / 2 {
+                e as f64 - (minus_one as f64 + 1.0)
+            } else {
+                e as f64
+            };
+            squares += e * e;
+        }
+        let measured = (squares / TRIALS as f64).sqrt();
+        assert!(
+            (measured / predicted - 1.0).abs() < 0.15,
+            "measured {measured}, predicted {predicted}"
+        );
+    }
+}
+
+#[test]
+fn test_gadget_rlwe_params_rotate() {
+    type FieldT = U32FieldEval<132120577>;
+    type ValT = u32;
+
+    const CIPHER_MODULUS: ValT = FieldT::MODULUS_VALUE;
+    const PLAIN_MODULUS: ValT = 16;
+    const N: usize = 64;
+
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, PLAIN_MODULUS);
+
+    let params = GadgetRlweParameters::<FieldT> {
+        dimension: N,
+        modulus: CIPHER_MODULUS,
+        secret_key_type: RingSecretKeyType::Ternary,
+        noise_standard_deviation: 3.20,
+        basis: NonPowOf2ApproxSignedBasis::new(CIPHER_MODULUS, 4, None),
+        noise_distribution_type: NoiseDistributionType::Gaussian,
+    };
+    let ntt_table = params.generate_ntt_table().unwrap();
+
+    let sk = params.generate_secret_key(&mut rng);
+    let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+    let trace_key = params.generate_trace_key(&sk, &ntt_sk, Arc::clone(&ntt_table), &mut rng);
+
+    let m: Vec<ValT> = (&mut rng).sample_iter(distr).take(N).collect();
+    let cipher = params.encrypt_packed(&ntt_sk, &m, PLAIN_MODULUS, &ntt_table, &mut rng);
+
+    for k in [
+        0isize, 1, 5, 31, 32, 33, 63, 64, 65, -1, -5, -32, -63, -64, 200,
+    ] {
+        let rotated = params.rotate(&trace_key, &cipher, k);
+        let decrypted: Vec<ValT> =
+            params.decrypt_packed(&ntt_sk, &rotated, PLAIN_MODULUS, &ntt_table);
+
+        // the `i`-th message moves to `(i + k) mod N`
+        let mut expected = m.clone();
+        expected.rotate_right(k.rem_euclid(N as isize) as usize);
+        assert_eq!(decrypted, expected, "k = {k}");
+    }
+
+    // rotations compose
+    let once = params.rotate(&trace_key, &cipher, 10);
+    let twice = params.rotate(&trace_key, &once, -3);
+    let decrypted: Vec<ValT> = params.decrypt_packed(&ntt_sk, &twice, PLAIN_MODULUS, &ntt_table);
+    let mut expected = m.clone();
+    expected.rotate_right(7);
+    assert_eq!(decrypted, expected);
+}
+
+#[test]
+fn test_gadget_rlwe_params_slots() {
+    type FieldT = U32FieldEval<132120577>;
+
+    let mut rng = thread_rng();
+
+    let params = GadgetRlweParameters::<FieldT>::preset_128bit_tfhe();
+    let n = params.dimension();
+    let ntt_table = params.generate_ntt_table().unwrap();
+
+    let a = <FieldPolynomial<FieldT>>::random(n, &mut rng);
+    let b = <FieldPolynomial<FieldT>>::random(n, &mut rng);
+
+    let a_slots = params.to_slots(&a, &ntt_table);
+    let b_slots = params.to_slots(&b, &ntt_table);
+    assert_eq!(params.from_slots(&a_slots, &ntt_table), a);
+    assert_eq!(params.from_slots(&b_slots, &ntt_table), b);
+
+    let mut expected = FieldPolynomial::<FieldT>::zero(n);
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let product = FieldT::mul(x, y);
+            let k = i + j;
+            if k < n {
+                FieldT::add_assign(&mut expected[k], product);
+            } else {
+                FieldT::sub_assign(&mut expected[k - n], product);
+            }
+        }
+    }
+
+    assert_eq!(
+        params.from_slots(&(a_slots * b_slots), &ntt_table),
+        expected
+    );
+}
+
+#[test]
+fn test_lwe_params_failure_probability() {
+    type CipherT = u16;
+
+    let mut rng = thread_rng();
+
+    let modulus = PowOf2Modulus::<CipherT>::new(256);
+    let params = LweParameters::new_unchecked(16, 4, modulus, LweSecretKeyType::Binary, 8.0);
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    // Summing 16 fresh ciphertexts takes the deviation from `8` to the threshold `q/2t = 32`.
+    const TERMS: usize = 16;
+    let additions: NoiseBudgetPlan = core::iter::repeat(NoiseOperation::AddFresh)
+        .take(TERMS - 1)
+        .collect();
+    assert_eq!(additions.variance_factor(), TERMS as f64);
+
+    let scaled = NoiseBudgetPlan::new()
+        .then(NoiseOperation::AddConstant)
+        .then(NoiseOperation::MulScalar(-4));
+    assert_eq!(
+        params.failure_probability(&additions),
+        params.failure_probability(&scaled)
+    );
+
+    let fresh = params.failure_probability(&NoiseBudgetPlan::new());
+    let expected = params.failure_probability(&additions);
+    assert!(fresh < 1e-4);
+    assert!((expected - 0.3173).abs() < 1e-3);
+
+    const TRIALS: usize = 4000;
+    let failures = (0..TRIALS)
+        .filter(|_| {
+            let ciphertexts: Vec<_> = (0..TERMS)
+                .map(|_| params.encrypt(&sk, 0u16, &mut rng))
+                .collect();
+            let terms: Vec<_> = ciphertexts.iter().map(|c| (1, c)).collect();
+            let sum = params.linear_combine(&terms);
+            params.decrypt::<CipherT>(&sk, &sum) != 0
+        })
+        .count();
+
+    let ratio = failures as f64 / (TRIALS as f64 * expected);
+    assert!((0.8..1.25).contains(&ratio), "ratio {ratio}");
+}
+
+#[test]
+fn test_lwe_params_trivial_encrypt() {
+    type CipherT = u16;
+
+    let mut rng = thread_rng();
+
+    let params = LweParameters::preset_128bit_tfhe();
+    let q = params.cipher_modulus;
+    let t = params.plain_modulus_value();
+    let sk = LweSecretKey::generate(&params, &mut rng);
+
+    for constant in 0..t {
+        let trivial = params.trivial_encrypt(constant);
+        assert!(trivial.a().iter().all(|&a| a == 0));
+        let decrypted: CipherT = params.decrypt(&sk, &trivial);
+        assert_eq!(decrypted, constant);
+
+        for message in 0..t {
+            let c = params.encrypt(&sk, message, &mut rng);
+            let (_, noise): (CipherT, CipherT) = sk.decrypt_with_noise(&c, &params);
+
+            let sum = c.add_reduce_component_wise_ref(&trivial, q);
+            let (decrypted, sum_noise): (CipherT, CipherT) = sk.decrypt_with_noise(&sum, &params);
+            assert_eq!(decrypted, params.plain_add(message, constant));
+            assert_eq!(sum_noise, noise);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+#![cfg(feature = "serde")]
+
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis,
+    modulus::{BarrettModulus, PowOf2Modulus},
+    ntt::NumberTheoryTransform,
+    polynomial::FieldPolynomial,
+    reduce::ModulusValue,
+    Field, U32FieldEval,
+};
+use fhe_core::{
+    FHECoreError, GadgetRlweParameters, KeySwitchingParameters, LweParameters, LweSecretKeyType,
+    NoiseDistributionType, RingSecretKeyType, PARAMETERS_FORMAT_VERSION,
+};
+use rand::thread_rng;
+
+type FieldT = U32FieldEval<132120577>;
+
+fn lwe_params() -> LweParameters<u16, PowOf2Modulus<u16>> {
+    LweParameters::new_unchecked(
+        512,
+        4,
+        PowOf2Modulus::new(1 << 14),
+        LweSecretKeyType::Binary,
+        3.20,
+    )
+}
+
+fn gadget_rlwe_params() -> GadgetRlweParameters<FieldT> {
+    GadgetRlweParameters {
+        dimension: 1024,
+        modulus: FieldT::MODULUS_VALUE,
+        secret_key_type: RingSecretKeyType::Ternary,
+        noise_standard_deviation: 6.40,
+        basis: NonPowOf2ApproxSignedBasis::new(FieldT::MODULUS_VALUE, 7, None),
+        noise_distribution_type: NoiseDistributionType::Gaussian,
+    }
+}
+
+fn key_switching_params() -> KeySwitchingParameters {
+    KeySwitchingParameters {
+        input_cipher_dimension: 1024,
+        output_cipher_dimension: 512,
+        log_modulus: 14,
+        log_basis: 2,
+        reverse_length: Some(6),
+        noise_standard_deviation: 6.40,
+    }
+}
+
+#[test]
+fn test_modulus_value_round_trip() {
+    for value in [
+        ModulusValue::Native,
+        ModulusValue::PowerOf2(1u32 << 14),
+        ModulusValue::Prime(FieldT::MODULUS_VALUE),
+        ModulusValue::Others(1000),
+    ] {
+        let bytes = bincode::serialize(&value).unwrap();
+        let loaded: ModulusValue<u32> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(loaded, value);
+    }
+}
+
+#[test]
+fn test_lwe_params_round_trip() {
+    let mut params = lwe_params();
+    params.noise_distribution_type = NoiseDistributionType::Ternary;
+
+    let mut bytes = Vec::new();
+    params.save(&mut bytes).unwrap();
+    let loaded = <LweParameters<u16, PowOf2Modulus<u16>>>::load(bytes.as_slice()).unwrap();
+
+    assert_eq!(loaded.dimension, params.dimension);
+    assert_eq!(loaded.plain_modulus_value, params.plain_modulus_value);
+    assert_eq!(loaded.cipher_modulus_value, params.cipher_modulus_value);
+    assert_eq!(
+        loaded.cipher_modulus_minus_one,
+        params.cipher_modulus_minus_one
+    );
+    assert_eq!(loaded.secret_key_type, params.secret_key_type);
+    assert_eq!(
+        loaded.noise_standard_deviation,
+        params.noise_standard_deviation
+    );
+    assert_eq!(
+        loaded.noise_distribution_type,
+        params.noise_distribution_type
+    );
+
+    // the modulus is rebuilt from its value
+    let params = LweParameters::new_unchecked(
+        512,
+        8u32,
+        BarrettModulus::<u32>::new(FieldT::MODULUS_VALUE),
+        LweSecretKeyType::Ternary,
+        3.20,
+    );
+    let bytes = bincode::serialize(&params).unwrap();
+    let loaded: LweParameters<u32, BarrettModulus<u32>> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(loaded.cipher_modulus.value(), FieldT::MODULUS_VALUE);
+    assert_eq!(loaded.cipher_modulus.ratio(), params.cipher_modulus.ratio());
+}
+
+#[test]
+fn test_gadget_rlwe_params_round_trip() {
+    let params = gadget_rlwe_params();
+
+    let mut bytes = Vec::new();
+    params.save(&mut bytes).unwrap();
+    let loaded = <GadgetRlweParameters<FieldT>>::load(bytes.as_slice()).unwrap();
+
+    assert_eq!(loaded.dimension, params.dimension);
+    assert_eq!(loaded.modulus, params.modulus);
+    assert_eq!(loaded.secret_key_type, params.secret_key_type);
+    assert_eq!(
+        loaded.noise_standard_deviation,
+        params.noise_standard_deviation
+    );
+    assert_eq!(loaded.basis, params.basis);
+    assert_eq!(loaded.basis.log_basis(), params.basis.log_basis());
+    assert_eq!(
+        loaded.noise_distribution_type,
+        params.noise_distribution_type
+    );
+
+    // the ntt table is rebuilt deterministically
+    let poly = FieldPolynomial::<FieldT>::random(params.dimension, &mut thread_rng());
+    let table = params.generate_ntt_table().unwrap();
+    let loaded_table = loaded.generate_ntt_table().unwrap();
+    assert_eq!(table.transform(&poly), loaded_table.transform(&poly));
+}
+
+#[test]
+fn test_key_switching_params_round_trip() {
+    let params = key_switching_params();
+
+    let mut bytes = Vec::new();
+    params.save(&mut bytes).unwrap();
+    let loaded = KeySwitchingParameters::load(bytes.as_slice()).unwrap();
+
+    assert_eq!(loaded.input_cipher_dimension, params.input_cipher_dimension);
+    assert_eq!(
+        loaded.output_cipher_dimension,
+        params.output_cipher_dimension
+    );
+    assert_eq!(loaded.log_modulus, params.log_modulus);
+    assert_eq!(loaded.log_basis, params.log_basis);
+    assert_eq!(loaded.reverse_length, params.reverse_length);
+    assert_eq!(
+        loaded.noise_standard_deviation,
+        params.noise_standard_deviation
+    );
+}
+
+#[test]
+fn test_truncated_params() {
+    let mut lwe_bytes = Vec::new();
+    lwe_params().save(&mut lwe_bytes).unwrap();
+    let mut rlwe_bytes = Vec::new();
+    gadget_rlwe_params().save(&mut rlwe_bytes).unwrap();
+    let mut ks_bytes = Vec::new();
+    key_switching_params().save(&mut ks_bytes).unwrap();
+
+    for len in 0..lwe_bytes.len() {
+        let result = <LweParameters<u16, PowOf2Modulus<u16>>>::load(&lwe_bytes[..len]);
+        assert!(matches!(result, Err(FHECoreError::Serialization(_))));
+    }
+    for len in 0..rlwe_bytes.len() {
+        let result = <GadgetRlweParameters<FieldT>>::load(&rlwe_bytes[..len]);
+        assert!(matches!(result, Err(FHECoreError::Serialization(_))));
+    }
+    for len in 0..ks_bytes.len() {
+        let result = KeySwitchingParameters::load(&ks_bytes[..len]);
+        assert!(matches!(result, Err(FHECoreError::Serialization(_))));
+    }
+}
+
+#[test]
+fn test_params_version_mismatch() {
+    let mut bytes = Vec::new();
+    lwe_params().save(&mut bytes).unwrap();
+
+    // the version is the first field
+    bytes[..4].copy_from_slice(&(PARAMETERS_FORMAT_VERSION + 1).to_le_bytes());
+
+    let result = <LweParameters<u16, PowOf2Modulus<u16>>>::load(bytes.as_slice());
+    assert!(matches!(
+        result,
+        Err(FHECoreError::ParametersVersionMismatch { expected, found })
+            if expected == PARAMETERS_FORMAT_VERSION && found == PARAMETERS_FORMAT_VERSION + 1
+    ));
+}
+
+#[test]
+fn test_corrupted_params() {
+    let mut bytes = Vec::new();
+    gadget_rlwe_params().save(&mut bytes).unwrap();
+
+    // the dimension follows the version
+    bytes[4..12].copy_from_slice(&1000u64.to_le_bytes());
+
+    let result = <GadgetRlweParameters<FieldT>>::load(bytes.as_slice());
+    assert!(matches!(result, Err(FHECoreError::CorruptedParameters(_))));
+}
+
+#[test]
+fn test_mismatched_lwe_modulus() {
+    let params = lwe_params();
+    let mut bytes = Vec::new();
+    params.save(&mut bytes).unwrap();
+
+    // the modulus follows the version, the dimension and the plain modulus,
+    // turn `PowerOf2(2^14)` into `Prime(2^14)`
+    let header = (
+        PARAMETERS_FORMAT_VERSION,
+        params.dimension,
+        params.plain_modulus_value,
+    );
+    let offset = bincode::serialized_size(&header).unwrap() as usize;
+    let stored = bincode::serialize(&params.cipher_modulus_value).unwrap();
+    let tampered = bincode::serialize(&ModulusValue::Prime(1u16 << 14)).unwrap();
+    assert_eq!(stored.len(), tampered.len());
+    assert_eq!(bytes[offset..offset + stored.len()], stored);
+    bytes[offset..offset + stored.len()].copy_from_slice(&tampered);
+
+    let result = <LweParameters<u16, PowOf2Modulus<u16>>>::load(bytes.as_slice());
+    assert!(matches!(result, Err(FHECoreError::CorruptedParameters(_))));
+
+    // a prime modulus can't be loaded as a power of 2 modulus
+    let params = LweParameters::new_unchecked(
+        512,
+        8u32,
+        BarrettModulus::<u32>::new(FieldT::MODULUS_VALUE),
+        LweSecretKeyType::Ternary,
+        3.20,
+    );
+    let mut bytes = Vec::new();
+    params.save(&mut bytes).unwrap();
+    let result = <LweParameters<u32, PowOf2Modulus<u32>>>::load(bytes.as_slice());
+    assert!(matches!(result, Err(FHECoreError::CorruptedParameters(_))));
+}
@@ -2,11 +2,10 @@ use algebra::{
     decompose::{NonPowOf2ApproxSignedBasis, SignedOnceDecompose},
     ntt::NumberTheoryTransform,
     polynomial::{FieldNttPolynomial, FieldPolynomial},
-    random::DiscreteGaussian,
     reduce::ReduceAddAssign,
     Field, NttField,
 };
-use rand::{CryptoRng, Rng};
+use rand::{distributions::Distribution, CryptoRng, Rng};
 
 use crate::{utils::PolyDecomposeSpace, NttRlwe, Rlwe};
 
@@ -210,18 +209,19 @@ impl<F: NttField> GadgetRlwe<F> {
     }
 
     /// Generate a `GadgetRlwe<F>` sample which encrypts `0`.
-    pub fn generate_random_zero_sample<R>(
+    pub fn generate_random_zero_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let data: Vec<Rlwe<F>> = (0..basis.decompose_length())
-            .map(|_| <Rlwe<F>>::generate_random_zero_sample(secret_key, gaussian, ntt_table, rng))
+            .map(|_| <Rlwe<F>>::generate_random_zero_sample(secret_key, noise, ntt_table, rng))
             .collect();
         Self {
             data,
@@ -230,21 +230,22 @@ impl<F: NttField> GadgetRlwe<F> {
     }
 
     /// Generate a [`GadgetRlwe<F>`] sample which encrypts `1`.
-    pub fn generate_random_one_sample<R>(
+    pub fn generate_random_one_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let data = basis
             .scalar_iter()
             .map(|scalar| {
                 let mut r =
-                    <Rlwe<F>>::generate_random_zero_sample(secret_key, gaussian, ntt_table, rng);
+                    <Rlwe<F>>::generate_random_zero_sample(secret_key, noise, ntt_table, rng);
                 <F as Field>::MODULUS.reduce_add_assign(&mut r.b_mut()[0], scalar);
                 r
             })
@@ -257,22 +258,23 @@ impl<F: NttField> GadgetRlwe<F> {
     }
 
     /// Generate a [`GadgetRlwe<F>`] sample which encrypts `poly`.
-    pub fn generate_random_poly_sample<R>(
+    pub fn generate_random_poly_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         poly: &FieldPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let data = basis
             .scalar_iter()
             .map(|scalar| {
                 let mut r =
-                    <Rlwe<F>>::generate_random_zero_sample(secret_key, gaussian, ntt_table, rng);
+                    <Rlwe<F>>::generate_random_zero_sample(secret_key, noise, ntt_table, rng);
                 r.b_mut().add_mul_scalar_assign(poly, scalar);
                 r
             })
@@ -285,21 +287,22 @@ impl<F: NttField> GadgetRlwe<F> {
     }
 
     /// Generate a [`GadgetRlwe<F>`] sample which encrypts `-s`.
-    pub fn generate_random_neg_secret_sample<R>(
+    pub fn generate_random_neg_secret_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let data = basis
             .scalar_iter()
             .map(|scalar| {
                 let mut r =
-                    <Rlwe<F>>::generate_random_zero_sample(secret_key, gaussian, ntt_table, rng);
+                    <Rlwe<F>>::generate_random_zero_sample(secret_key, noise, ntt_table, rng);
                 <F as Field>::MODULUS.reduce_add_assign(&mut r.a_mut()[0], scalar);
                 r
             })
@@ -2,11 +2,10 @@ use algebra::{
     decompose::{NonPowOf2ApproxSignedBasis, SignedOnceDecompose},
     ntt::NumberTheoryTransform,
     polynomial::{FieldNttPolynomial, FieldPolynomial},
-    random::DiscreteGaussian,
     reduce::ReduceAddAssign,
     Field, NttField,
 };
-use rand::{CryptoRng, Rng};
+use rand::{distributions::Distribution, CryptoRng, Rng};
 
 use crate::{utils::PolyDecomposeSpace, NttRlwe};
 
@@ -255,20 +254,19 @@ impl<F: NttField> NttGadgetRlwe<F> {
     }
 
     /// Generate a [`NttGadgetRlwe<F>`] sample which encrypts `0`.
-    pub fn generate_random_zero_sample<R>(
+    pub fn generate_random_zero_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let data = (0..basis.decompose_length())
-            .map(|_| {
-                <NttRlwe<F>>::generate_random_zero_sample(secret_key, gaussian, ntt_table, rng)
-            })
+            .map(|_| <NttRlwe<F>>::generate_random_zero_sample(secret_key, noise, ntt_table, rng))
             .collect();
         Self {
             data,
@@ -277,21 +275,22 @@ impl<F: NttField> NttGadgetRlwe<F> {
     }
 
     /// Generate a [`NttGadgetRlwe<F>`] sample which encrypts `1`.
-    pub fn generate_random_one_sample<R>(
+    pub fn generate_random_one_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let data = basis
             .scalar_iter()
             .map(|scalar| {
                 <NttRlwe<F>>::generate_random_value_sample(
-                    secret_key, scalar, gaussian, ntt_table, rng,
+                    secret_key, scalar, noise, ntt_table, rng,
                 )
             })
             .collect();
@@ -303,22 +302,23 @@ impl<F: NttField> NttGadgetRlwe<F> {
     }
 
     /// Generate a [`NttGadgetRlwe<F>`] sample which encrypts `poly`.
-    pub fn generate_random_poly_sample<R>(
+    pub fn generate_random_poly_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         poly: &FieldNttPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let data = basis
             .scalar_iter()
             .map(|scalar| {
                 let mut r =
-                    <NttRlwe<F>>::generate_random_zero_sample(secret_key, gaussian, ntt_table, rng);
+                    <NttRlwe<F>>::generate_random_zero_sample(secret_key, noise, ntt_table, rng);
                 r.b_mut().add_mul_scalar_assign(poly, scalar);
                 r
             })
@@ -331,21 +331,22 @@ impl<F: NttField> NttGadgetRlwe<F> {
     }
 
     /// Generate a [`NttGadgetRlwe<F>`] sample which encrypts `-s`.
-    pub fn generate_random_neg_secret_sample<R>(
+    pub fn generate_random_neg_secret_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let data = basis
             .scalar_iter()
             .map(|scalar| {
                 let mut r =
-                    <NttRlwe<F>>::generate_random_zero_sample(secret_key, gaussian, ntt_table, rng);
+                    <NttRlwe<F>>::generate_random_zero_sample(secret_key, noise, ntt_table, rng);
                 r.a_mut_slice()
                     .iter_mut()
                     .for_each(|v| F::MODULUS.reduce_add_assign(v, scalar));
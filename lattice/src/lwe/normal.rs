@@ -1,6 +1,5 @@
 use algebra::{
     integer::UnsignedInteger,
-    random::DiscreteGaussian,
     reduce::{
         Modulus, ReduceAdd, ReduceAddAssign, ReduceDotProduct, ReduceMulAdd, ReduceMulAssign,
         ReduceNeg, ReduceNegAssign, ReduceSub, ReduceSubAssign,
@@ -247,21 +246,22 @@ impl<T: UnsignedInteger> Lwe<T> {
 
     /// Generate a [`Lwe<T>`] sample which encrypts `0`.
     #[inline]
-    pub fn generate_random_zero_sample<M, R>(
+    pub fn generate_random_zero_sample<M, R, D>(
         secret_key: &[T],
         modulus: M,
-        gaussian: DiscreteGaussian<T>,
+        noise: D,
         rng: &mut R,
     ) -> Self
     where
         M: Copy + Modulus<T> + ReduceDotProduct<T, Output = T> + ReduceAdd<T, Output = T>,
         R: rand::Rng + rand::CryptoRng,
+        D: Distribution<T> + Copy,
     {
         let len = secret_key.len();
         let uniform = Uniform::new_inclusive(T::ZERO, modulus.modulus_minus_one());
 
         let a: Vec<T> = uniform.sample_iter(&mut *rng).take(len).collect();
-        let e = gaussian.sample(rng);
+        let e = noise.sample(rng);
 
         let b = modulus.reduce_dot_product(a.as_slice(), secret_key);
         let b = modulus.reduce_add(b, e);
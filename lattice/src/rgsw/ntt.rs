@@ -1,8 +1,7 @@
 use algebra::{
-    decompose::NonPowOf2ApproxSignedBasis, polynomial::FieldNttPolynomial,
-    random::DiscreteGaussian, Field, NttField,
+    decompose::NonPowOf2ApproxSignedBasis, polynomial::FieldNttPolynomial, Field, NttField,
 };
-use rand::{CryptoRng, Rng};
+use rand::{distributions::Distribution, CryptoRng, Rng};
 
 use crate::NttGadgetRlwe;
 
@@ -151,43 +150,45 @@ impl<F: NttField> NttRgsw<F> {
     }
 
     /// Generate a [`NttRgsw<F>`] sample which encrypts `0`.
-    pub fn generate_random_zero_sample<R>(
+    pub fn generate_random_zero_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         Self {
             minus_s_m: <NttGadgetRlwe<F>>::generate_random_zero_sample(
-                secret_key, basis, gaussian, ntt_table, rng,
+                secret_key, basis, noise, ntt_table, rng,
             ),
             m: <NttGadgetRlwe<F>>::generate_random_zero_sample(
-                secret_key, basis, gaussian, ntt_table, rng,
+                secret_key, basis, noise, ntt_table, rng,
             ),
         }
     }
 
     /// Generate a [`NttRgsw<F>`] sample which encrypts `1`.
-    pub fn generate_random_one_sample<R>(
+    pub fn generate_random_one_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         Self {
             minus_s_m: <NttGadgetRlwe<F>>::generate_random_neg_secret_sample(
-                secret_key, basis, gaussian, ntt_table, rng,
+                secret_key, basis, noise, ntt_table, rng,
             ),
             m: <NttGadgetRlwe<F>>::generate_random_one_sample(
-                secret_key, basis, gaussian, ntt_table, rng,
+                secret_key, basis, noise, ntt_table, rng,
             ),
         }
     }
@@ -1,11 +1,10 @@
 use algebra::{
     ntt::NumberTheoryTransform,
     polynomial::{FieldNttPolynomial, FieldPolynomial},
-    random::DiscreteGaussian,
     reduce::{ReduceAddAssign, ReduceNeg, ReduceNegAssign, ReduceSubAssign},
     Field, NttField,
 };
-use rand::{CryptoRng, Rng};
+use rand::{distributions::Distribution, CryptoRng, Rng};
 
 use super::NttRlwe;
 
@@ -516,14 +515,15 @@ impl<F: NttField> Rlwe<F> {
     }
 
     /// Generate a `Rlwe<F>` sample which encrypts `0`.
-    pub fn generate_random_zero_sample<R>(
+    pub fn generate_random_zero_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let rlwe_dimension = secret_key.coeff_count();
         let a = <FieldPolynomial<F>>::random(rlwe_dimension, rng);
@@ -531,7 +531,7 @@ impl<F: NttField> Rlwe<F> {
         let mut a_ntt = ntt_table.transform(&a);
         a_ntt *= secret_key;
 
-        let mut e = <FieldPolynomial<F>>::random_gaussian(rlwe_dimension, gaussian, rng);
+        let mut e = <FieldPolynomial<F>>::random_noise(rlwe_dimension, noise, rng);
         e += ntt_table.inverse_transform_inplace(a_ntt);
 
         Self { a, b: e }
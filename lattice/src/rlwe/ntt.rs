@@ -3,11 +3,10 @@ use std::ops::MulAssign;
 use algebra::{
     ntt::NumberTheoryTransform,
     polynomial::{FieldNttPolynomial, FieldPolynomial},
-    random::DiscreteGaussian,
     reduce::ReduceAddAssign,
     Field, NttField,
 };
-use rand::{CryptoRng, Rng};
+use rand::{distributions::Distribution, CryptoRng, Rng};
 
 use crate::{utils::PolyDecomposeSpace, NttGadgetRlwe};
 
@@ -402,19 +401,20 @@ impl<F: NttField> NttRlwe<F> {
     }
 
     /// Generate a [`NttRlwe<F>`] sample which encrypts `0`.
-    pub fn generate_random_zero_sample<R>(
+    pub fn generate_random_zero_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let rlwe_dimension = secret_key.coeff_count();
         let a = <FieldNttPolynomial<F>>::random(rlwe_dimension, rng);
 
-        let e = <FieldPolynomial<F>>::random_gaussian(rlwe_dimension, gaussian, rng);
+        let e = <FieldPolynomial<F>>::random_noise(rlwe_dimension, noise, rng);
         let mut e = ntt_table.transform_inplace(e);
         e.add_mul_assign(&a, secret_key);
 
@@ -422,20 +422,21 @@ impl<F: NttField> NttRlwe<F> {
     }
 
     /// Generate a [`NttRlwe<F>`] sample which encrypts `value`.
-    pub fn generate_random_value_sample<R>(
+    pub fn generate_random_value_sample<R, D>(
         secret_key: &FieldNttPolynomial<F>,
         value: <F as Field>::ValueT,
-        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        noise: D,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
         R: Rng + CryptoRng,
+        D: Distribution<<F as Field>::ValueT> + Copy,
     {
         let rlwe_dimension = secret_key.coeff_count();
         let a = <FieldNttPolynomial<F>>::random(rlwe_dimension, rng);
 
-        let mut e = <FieldPolynomial<F>>::random_gaussian(rlwe_dimension, gaussian, rng);
+        let mut e = <FieldPolynomial<F>>::random_noise(rlwe_dimension, noise, rng);
         F::MODULUS.reduce_add_assign(&mut e[0], value);
 
         let mut b = ntt_table.transform_inplace(e);